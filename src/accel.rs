@@ -1,6 +1,6 @@
 use crate::constants::G;
 use crate::time::Frequency;
-use crate::{Duration, Length, Mass, Speed};
+use crate::{Duration, Length, Mass, Speed, Squared};
 
 scalar! {
     struct Acceleration(f64) {
@@ -14,7 +14,6 @@ scalar_div!(Acceleration | Frequency = Speed);
 impl Acceleration {
     #[inline]
     pub fn from_gravity(mass: Mass, distance: Length) -> Self {
-        let g = G * mass.value / (distance.value * distance.value);
-        Acceleration::in_m_per_s2(g)
+        G * mass / distance.squared()
     }
 }
@@ -8,6 +8,14 @@ scalar! {
     }
 }
 
+scalar! {
+    struct AmountInv(f64) {
+        fn in_mol_inv(per_mole) -> Self;
+    }
+}
+
+scalar_div!(f64 | Amount = AmountInv);
+
 scalar! {
     struct MolecularMass(f64) {
         fn in_kg_per_mol(kilograms_per_mole) -> Self;
@@ -1,7 +1,7 @@
 use super::{Duration, Length};
 use crate::constants::G;
 use crate::time::Frequency;
-use crate::Mass;
+use crate::{Mass, Sqrt, Squared};
 use rand::distributions::{Distribution, Standard};
 use rand::Rng;
 use std::f64::consts::{PI, TAU};
@@ -61,6 +61,12 @@ impl Angle {
     pub fn atan(value: f64) -> Angle {
         Angle::in_rad(value.atan())
     }
+
+    /// Four-quadrant arctangent of `y / x`, in the range `-PI..=PI`
+    #[inline]
+    pub fn atan2(y: f64, x: f64) -> Angle {
+        Angle::in_rad(y.atan2(x))
+    }
 }
 
 impl Distribution<Angle> for Standard {
@@ -79,8 +85,8 @@ scalar! {
 impl AngularSpeed {
     #[inline]
     pub fn of_orbit(mass: Mass, radius: Length) -> Self {
-        let r_cubed = radius.value * radius.value * radius.value;
-        Self::new((G * mass.value / r_cubed).sqrt())
+        let r_cubed = radius * radius.squared();
+        RAD * (G * mass / r_cubed).sqrt()
     }
 }
 
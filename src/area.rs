@@ -1,4 +1,4 @@
-use crate::{Length, LengthInv, New, Squared};
+use crate::{Length, LengthInv, Squared};
 use std::f64::consts::PI;
 
 pub const M2: Area = Area::in_m2(1.0);
@@ -0,0 +1,247 @@
+use crate::{ChronoDuration, DateTime, Duration, Frequency, Length, Mass, TimeIndex};
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+
+/// Maps game time to calendar dates and back.
+pub trait Calendar {
+    type Date: Display;
+
+    /// Returns `None` if the time falls outside the range the calendar can represent
+    fn date(&self, time: TimeIndex) -> Option<Self::Date>;
+
+    fn time_index(&self, date: &Self::Date) -> TimeIndex;
+
+    #[inline]
+    fn format(&self, time: TimeIndex) -> Option<String> {
+        self.date(time).map(|date| date.to_string())
+    }
+}
+
+/// The Gregorian calendar, with `TimeIndex::default()` falling on the epoch.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct GregorianCalendar {
+    pub epoch: DateTime,
+}
+
+impl GregorianCalendar {
+    #[inline]
+    pub const fn new(epoch: DateTime) -> Self {
+        Self { epoch }
+    }
+}
+
+impl Calendar for GregorianCalendar {
+    type Date = DateTime;
+
+    #[inline]
+    fn date(&self, time: TimeIndex) -> Option<DateTime> {
+        let elapsed = ChronoDuration::try_from(time.value).ok()?;
+        self.epoch.checked_add_signed(elapsed)
+    }
+
+    #[inline]
+    fn time_index(&self, date: &DateTime) -> TimeIndex {
+        TimeIndex::from_origin(Duration::from(*date - self.epoch))
+    }
+}
+
+/// A calendar built from the local day and year of a planet.
+///
+/// Years are counted from the epoch and days are counted from the start of each year,
+/// so a year that is not a whole number of days ends with a partial day.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PlanetaryCalendar {
+    pub epoch: TimeIndex,
+    pub day: Duration,
+    pub year: Duration,
+}
+
+impl PlanetaryCalendar {
+    #[inline]
+    pub fn new(epoch: TimeIndex, day: Duration, year: Duration) -> Self {
+        debug_assert!(day > Duration::zero());
+        debug_assert!(year > Duration::zero());
+
+        Self { epoch, day, year }
+    }
+
+    /// Builds a calendar for a planet in a circular orbit around a star.
+    ///
+    /// The local (solar) day is derived from the sidereal `rotation_period`, which is negative
+    /// for retrograde rotation, and the year from the orbital period. A tidally locked planet
+    /// has no solar day, so its calendar counts sidereal days instead.
+    pub fn of_planet(
+        epoch: TimeIndex,
+        rotation_period: Duration,
+        orbit_radius: Length,
+        star_mass: Mass,
+    ) -> Self {
+        let year = Duration::of_orbit(orbit_radius, star_mass);
+        let solar_frequency = 1.0 / rotation_period - 1.0 / year;
+        let day = if solar_frequency == Frequency::zero() {
+            rotation_period.abs()
+        } else {
+            (1.0 / solar_frequency).abs()
+        };
+
+        Self::new(epoch, day, year)
+    }
+
+    /// The number of whole local days in a year
+    #[inline]
+    pub fn days_per_year(&self) -> i64 {
+        (self.year / self.day) as i64
+    }
+}
+
+impl Calendar for PlanetaryCalendar {
+    type Date = PlanetaryDate;
+
+    fn date(&self, time: TimeIndex) -> Option<PlanetaryDate> {
+        let elapsed = time - self.epoch;
+
+        let year = (elapsed / self.year).floor();
+        let into_year = elapsed - year * self.year;
+
+        let day = (into_year / self.day).floor();
+        let into_day = into_year - day * self.day;
+
+        if !year.is_finite() || year.abs() >= i64::MAX as f64 {
+            return None;
+        }
+
+        Some(PlanetaryDate {
+            year: year as i64,
+            day: day as i64,
+            time_of_day: into_day / self.day,
+        })
+    }
+
+    #[inline]
+    fn time_index(&self, date: &PlanetaryDate) -> TimeIndex {
+        self.epoch
+            + date.year as f64 * self.year
+            + date.day as f64 * self.day
+            + date.time_of_day * self.day
+    }
+}
+
+/// A date in a `PlanetaryCalendar`, formatted as `Year 12, Day 045, 06:30`.
+///
+/// The local day is divided into 24 local hours for display.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PlanetaryDate {
+    /// Whole years since the epoch
+    pub year: i64,
+    /// Whole days since the start of the year, starting at zero
+    pub day: i64,
+    /// Fraction of the local day that has passed
+    pub time_of_day: f64,
+}
+
+impl Display for PlanetaryDate {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let minutes = (self.time_of_day * 24.0 * 60.0) as i64;
+        write!(
+            f,
+            "Year {}, Day {:03}, {:02}:{:02}",
+            self.year,
+            self.day + 1,
+            minutes / 60,
+            minutes % 60
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::catalogue::{EARTH, MARS, SUN};
+    use crate::{AU, DAY, HR, YR};
+    use chrono::NaiveDate;
+
+    fn epoch() -> DateTime {
+        NaiveDate::from_ymd_opt(2000, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn gregorian_round_trip() {
+        let calendar = GregorianCalendar::new(epoch());
+        let time = TimeIndex::default() + 1.5 * DAY;
+
+        let date = calendar.date(time).unwrap();
+        let expected = NaiveDate::from_ymd_opt(2000, 1, 2)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+
+        assert_eq!(expected, date);
+        assert_eq!(time, calendar.time_index(&date));
+        assert_eq!("2000-01-02 12:00:00", calendar.format(time).unwrap());
+    }
+
+    #[test]
+    fn gregorian_out_of_range() {
+        let calendar = GregorianCalendar::new(epoch());
+
+        assert_eq!(None, calendar.date(TimeIndex::default() + 1e6 * YR));
+        assert_eq!(None, calendar.date(TimeIndex::default() - 1e300 * YR));
+    }
+
+    #[test]
+    fn earth_solar_day() {
+        let calendar =
+            PlanetaryCalendar::of_planet(TimeIndex::default(), EARTH.rotation_period, AU, SUN.mass);
+
+        assert!((calendar.day - DAY).abs() < Duration::in_s(1.0));
+        assert_eq!(365, calendar.days_per_year());
+    }
+
+    #[test]
+    fn planetary_date() {
+        let mars = MARS.revolution.unwrap();
+        let calendar = PlanetaryCalendar::of_planet(
+            TimeIndex::default(),
+            MARS.rotation_period,
+            mars.semi_major_axis,
+            mars.primary.mass,
+        );
+
+        let date = PlanetaryDate {
+            year: 2,
+            day: 44,
+            time_of_day: 0.25,
+        };
+        let round_trip = calendar.date(calendar.time_index(&date)).unwrap();
+
+        assert_eq!("Year 2, Day 045, 06:00", date.to_string());
+        assert_eq!((2, 44), (round_trip.year, round_trip.day));
+        assert!((round_trip.time_of_day - 0.25).abs() < 1e-6);
+        assert!(calendar.day > 24.0 * HR);
+
+        let before_epoch = TimeIndex::default() - calendar.year;
+        assert_eq!(
+            "Year -1, Day 001, 00:00",
+            calendar.format(before_epoch).unwrap()
+        );
+    }
+
+    #[test]
+    fn tidally_locked_planet() {
+        let year = Duration::of_orbit(AU, SUN.mass);
+        let calendar = PlanetaryCalendar::of_planet(TimeIndex::default(), year, AU, SUN.mass);
+
+        assert_eq!(year, calendar.day);
+        assert_eq!(1, calendar.days_per_year());
+
+        let time = TimeIndex::default() + 2.5 * year;
+        let date = calendar.date(time).unwrap();
+
+        assert_eq!((2, 0), (date.year, date.day));
+        assert!((date.time_of_day - 0.5).abs() < 1e-9);
+        assert!((calendar.time_index(&date) - time).abs() < Duration::in_s(1e-3));
+    }
+}
@@ -0,0 +1,414 @@
+//! Reference data for the Sun, the planets, their major moons and some well-known stars.
+//!
+//! Values are rounded mean values from the NASA planetary fact sheets and the
+//! IAU nominal solar values.
+//! https://nssdc.gsfc.nasa.gov/planetary/factsheet/
+
+use crate::*;
+
+/// A natural body with enough data to place it in a system and estimate its climate.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Body {
+    pub name: &'static str,
+    pub mass: Mass,
+    /// Mean radius
+    pub radius: Length,
+    /// Mean surface temperature, or effective temperature for stars
+    pub temperature: Temperature,
+    /// Bond albedo
+    pub albedo: UnitInterval,
+    /// Sidereal rotation period, negative for retrograde rotation
+    pub rotation_period: Duration,
+    pub revolution: Option<Revolution>,
+}
+
+/// The orbit of a body around its primary.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Revolution {
+    pub primary: &'static Body,
+    pub semi_major_axis: Length,
+    /// Sidereal orbital period
+    pub period: Duration,
+}
+
+impl Body {
+    #[inline]
+    pub fn primary(&self) -> Option<&'static Body> {
+        self.revolution.map(|r| r.primary)
+    }
+
+    #[inline]
+    pub fn semi_major_axis(&self) -> Option<Length> {
+        self.revolution.map(|r| r.semi_major_axis)
+    }
+
+    #[inline]
+    pub fn orbital_period(&self) -> Option<Duration> {
+        self.revolution.map(|r| r.period)
+    }
+
+    #[inline]
+    pub fn surface_gravity(&self) -> Acceleration {
+        Acceleration::of_surface(self.mass, self.radius)
+    }
+
+    #[inline]
+    pub fn escape_velocity(&self) -> Speed {
+        Speed::of_escape(self.mass, self.radius)
+    }
+
+    #[inline]
+    pub fn sphere_of_influence(&self) -> Option<Length> {
+        self.revolution
+            .map(|r| Length::sphere_of_influence(r.semi_major_axis, self.mass, r.primary.mass))
+    }
+
+    /// Treats the orbit as circular
+    #[inline]
+    pub fn hill_sphere(&self) -> Option<Length> {
+        self.revolution
+            .map(|r| Length::hill_sphere(r.semi_major_axis, 0.0, self.mass, r.primary.mass))
+    }
+}
+
+/// Looks up a catalogued body by name, ignoring ASCII case.
+pub fn find(name: &str) -> Option<&'static Body> {
+    BODIES
+        .iter()
+        .copied()
+        .find(|body| body.name.eq_ignore_ascii_case(name))
+}
+
+pub const SUN: Body = Body {
+    name: "Sun",
+    mass: Mass::in_kg(1.988_47e30),
+    radius: Length::in_m(6.957e8),
+    temperature: Temperature::in_k(5772.0),
+    albedo: UnitInterval::clamp(0.0),
+    rotation_period: Duration::in_d(25.05),
+    revolution: None,
+};
+
+pub const MERCURY: Body = Body {
+    name: "Mercury",
+    mass: Mass::in_kg(3.3011e23),
+    radius: Length::in_m(2439.7e3),
+    temperature: Temperature::in_k(440.0),
+    albedo: UnitInterval::clamp(0.088),
+    rotation_period: Duration::in_d(58.646),
+    revolution: Some(Revolution {
+        primary: &SUN,
+        semi_major_axis: 0.387_098 * AU,
+        period: Duration::in_d(87.9691),
+    }),
+};
+
+pub const VENUS: Body = Body {
+    name: "Venus",
+    mass: Mass::in_kg(4.8675e24),
+    radius: Length::in_m(6051.8e3),
+    temperature: Temperature::in_k(737.0),
+    albedo: UnitInterval::clamp(0.76),
+    rotation_period: Duration::in_d(-243.0226),
+    revolution: Some(Revolution {
+        primary: &SUN,
+        semi_major_axis: 0.723_332 * AU,
+        period: Duration::in_d(224.701),
+    }),
+};
+
+pub const EARTH: Body = Body {
+    name: "Earth",
+    mass: Mass::in_kg(5.9722e24),
+    radius: Length::in_m(6371.0e3),
+    temperature: Temperature::in_k(288.0),
+    albedo: UnitInterval::clamp(0.306),
+    rotation_period: Duration::in_d(0.997_269_68),
+    revolution: Some(Revolution {
+        primary: &SUN,
+        semi_major_axis: 1.000_001 * AU,
+        period: Duration::in_d(365.256_363),
+    }),
+};
+
+pub const MARS: Body = Body {
+    name: "Mars",
+    mass: Mass::in_kg(6.4171e23),
+    radius: Length::in_m(3389.5e3),
+    temperature: Temperature::in_k(210.0),
+    albedo: UnitInterval::clamp(0.25),
+    rotation_period: Duration::in_d(1.025_957),
+    revolution: Some(Revolution {
+        primary: &SUN,
+        semi_major_axis: 1.523_679 * AU,
+        period: Duration::in_d(686.980),
+    }),
+};
+
+pub const JUPITER: Body = Body {
+    name: "Jupiter",
+    mass: Mass::in_kg(1.898_19e27),
+    radius: Length::in_m(69_911e3),
+    temperature: Temperature::in_k(165.0),
+    albedo: UnitInterval::clamp(0.503),
+    rotation_period: Duration::in_hr(9.925),
+    revolution: Some(Revolution {
+        primary: &SUN,
+        semi_major_axis: 5.2038 * AU,
+        period: Duration::in_d(4332.59),
+    }),
+};
+
+pub const SATURN: Body = Body {
+    name: "Saturn",
+    mass: Mass::in_kg(5.6834e26),
+    radius: Length::in_m(58_232e3),
+    temperature: Temperature::in_k(134.0),
+    albedo: UnitInterval::clamp(0.342),
+    rotation_period: Duration::in_hr(10.56),
+    revolution: Some(Revolution {
+        primary: &SUN,
+        semi_major_axis: 9.5826 * AU,
+        period: Duration::in_d(10_759.22),
+    }),
+};
+
+pub const URANUS: Body = Body {
+    name: "Uranus",
+    mass: Mass::in_kg(8.6810e25),
+    radius: Length::in_m(25_362e3),
+    temperature: Temperature::in_k(76.0),
+    albedo: UnitInterval::clamp(0.300),
+    rotation_period: Duration::in_hr(-17.24),
+    revolution: Some(Revolution {
+        primary: &SUN,
+        semi_major_axis: 19.191_26 * AU,
+        period: Duration::in_d(30_688.5),
+    }),
+};
+
+pub const NEPTUNE: Body = Body {
+    name: "Neptune",
+    mass: Mass::in_kg(1.024_13e26),
+    radius: Length::in_m(24_622e3),
+    temperature: Temperature::in_k(72.0),
+    albedo: UnitInterval::clamp(0.290),
+    rotation_period: Duration::in_hr(16.11),
+    revolution: Some(Revolution {
+        primary: &SUN,
+        semi_major_axis: 30.07 * AU,
+        period: Duration::in_d(60_195.0),
+    }),
+};
+
+pub const MOON: Body = Body {
+    name: "Moon",
+    mass: Mass::in_kg(7.342e22),
+    radius: Length::in_m(1737.4e3),
+    temperature: Temperature::in_k(220.0),
+    albedo: UnitInterval::clamp(0.11),
+    rotation_period: Duration::in_d(27.321_661),
+    revolution: Some(Revolution {
+        primary: &EARTH,
+        semi_major_axis: Length::in_m(384_399e3),
+        period: Duration::in_d(27.321_661),
+    }),
+};
+
+pub const IO: Body = Body {
+    name: "Io",
+    mass: Mass::in_kg(8.931_938e22),
+    radius: Length::in_m(1821.6e3),
+    temperature: Temperature::in_k(110.0),
+    albedo: UnitInterval::clamp(0.63),
+    rotation_period: Duration::in_d(1.769_138),
+    revolution: Some(Revolution {
+        primary: &JUPITER,
+        semi_major_axis: Length::in_m(421_700e3),
+        period: Duration::in_d(1.769_138),
+    }),
+};
+
+pub const EUROPA: Body = Body {
+    name: "Europa",
+    mass: Mass::in_kg(4.799_844e22),
+    radius: Length::in_m(1560.8e3),
+    temperature: Temperature::in_k(102.0),
+    albedo: UnitInterval::clamp(0.67),
+    rotation_period: Duration::in_d(3.551_181),
+    revolution: Some(Revolution {
+        primary: &JUPITER,
+        semi_major_axis: Length::in_m(670_900e3),
+        period: Duration::in_d(3.551_181),
+    }),
+};
+
+pub const GANYMEDE: Body = Body {
+    name: "Ganymede",
+    mass: Mass::in_kg(1.4819e23),
+    radius: Length::in_m(2634.1e3),
+    temperature: Temperature::in_k(110.0),
+    albedo: UnitInterval::clamp(0.43),
+    rotation_period: Duration::in_d(7.154_553),
+    revolution: Some(Revolution {
+        primary: &JUPITER,
+        semi_major_axis: Length::in_m(1_070_400e3),
+        period: Duration::in_d(7.154_553),
+    }),
+};
+
+pub const CALLISTO: Body = Body {
+    name: "Callisto",
+    mass: Mass::in_kg(1.075_938e23),
+    radius: Length::in_m(2410.3e3),
+    temperature: Temperature::in_k(134.0),
+    albedo: UnitInterval::clamp(0.22),
+    rotation_period: Duration::in_d(16.689_018),
+    revolution: Some(Revolution {
+        primary: &JUPITER,
+        semi_major_axis: Length::in_m(1_882_700e3),
+        period: Duration::in_d(16.689_018),
+    }),
+};
+
+pub const TITAN: Body = Body {
+    name: "Titan",
+    mass: Mass::in_kg(1.3452e23),
+    radius: Length::in_m(2574.73e3),
+    temperature: Temperature::in_k(94.0),
+    albedo: UnitInterval::clamp(0.22),
+    rotation_period: Duration::in_d(15.945),
+    revolution: Some(Revolution {
+        primary: &SATURN,
+        semi_major_axis: Length::in_m(1_221_870e3),
+        period: Duration::in_d(15.945),
+    }),
+};
+
+pub const SIRIUS_A: Body = Body {
+    name: "Sirius A",
+    mass: 2.063 * SUN.mass,
+    radius: 1.711 * SUN.radius,
+    temperature: Temperature::in_k(9940.0),
+    albedo: UnitInterval::clamp(0.0),
+    // estimated from the projected rotational velocity
+    rotation_period: Duration::in_d(5.4),
+    revolution: None,
+};
+
+pub const ALPHA_CENTAURI_A: Body = Body {
+    name: "Alpha Centauri A",
+    mass: 1.0788 * SUN.mass,
+    radius: 1.2175 * SUN.radius,
+    temperature: Temperature::in_k(5790.0),
+    albedo: UnitInterval::clamp(0.0),
+    rotation_period: Duration::in_d(22.0),
+    revolution: None,
+};
+
+pub const ALPHA_CENTAURI_B: Body = Body {
+    name: "Alpha Centauri B",
+    mass: 0.9092 * SUN.mass,
+    radius: 0.8591 * SUN.radius,
+    temperature: Temperature::in_k(5260.0),
+    albedo: UnitInterval::clamp(0.0),
+    rotation_period: Duration::in_d(36.0),
+    revolution: Some(Revolution {
+        primary: &ALPHA_CENTAURI_A,
+        semi_major_axis: 23.3 * AU,
+        period: Duration::in_yr(79.91),
+    }),
+};
+
+pub const PROXIMA_CENTAURI: Body = Body {
+    name: "Proxima Centauri",
+    mass: 0.1221 * SUN.mass,
+    radius: 0.1542 * SUN.radius,
+    temperature: Temperature::in_k(3042.0),
+    albedo: UnitInterval::clamp(0.0),
+    rotation_period: Duration::in_d(83.0),
+    revolution: None,
+};
+
+pub const VEGA: Body = Body {
+    name: "Vega",
+    mass: 2.135 * SUN.mass,
+    radius: 2.362 * SUN.radius,
+    temperature: Temperature::in_k(9602.0),
+    albedo: UnitInterval::clamp(0.0),
+    rotation_period: Duration::in_hr(16.5),
+    revolution: None,
+};
+
+pub const BETELGEUSE: Body = Body {
+    name: "Betelgeuse",
+    mass: 16.5 * SUN.mass,
+    radius: 764.0 * SUN.radius,
+    temperature: Temperature::in_k(3600.0),
+    albedo: UnitInterval::clamp(0.0),
+    rotation_period: Duration::in_yr(36.0),
+    revolution: None,
+};
+
+pub const PLANETS: [&Body; 8] = [
+    &MERCURY, &VENUS, &EARTH, &MARS, &JUPITER, &SATURN, &URANUS, &NEPTUNE,
+];
+
+pub const MOONS: [&Body; 6] = [&MOON, &IO, &EUROPA, &GANYMEDE, &CALLISTO, &TITAN];
+
+pub const STARS: [&Body; 7] = [
+    &SUN,
+    &SIRIUS_A,
+    &ALPHA_CENTAURI_A,
+    &ALPHA_CENTAURI_B,
+    &PROXIMA_CENTAURI,
+    &VEGA,
+    &BETELGEUSE,
+];
+
+const BODIES: [&Body; 21] = [
+    &SUN,
+    &MERCURY,
+    &VENUS,
+    &EARTH,
+    &MARS,
+    &JUPITER,
+    &SATURN,
+    &URANUS,
+    &NEPTUNE,
+    &MOON,
+    &IO,
+    &EUROPA,
+    &GANYMEDE,
+    &CALLISTO,
+    &TITAN,
+    &SIRIUS_A,
+    &ALPHA_CENTAURI_A,
+    &ALPHA_CENTAURI_B,
+    &PROXIMA_CENTAURI,
+    &VEGA,
+    &BETELGEUSE,
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn periods_match_keplers_third_law() {
+        for body in PLANETS.iter().chain(MOONS.iter()) {
+            let revolution = body.revolution.unwrap();
+            let expected = Duration::of_orbit(revolution.semi_major_axis, revolution.primary.mass);
+
+            let error = (revolution.period - expected) / expected;
+            assert!(error.abs() < 0.01, "{}: {}", body.name, error);
+        }
+    }
+
+    #[test]
+    fn find_by_name() {
+        assert_eq!(Some(&EARTH), find("earth"));
+        assert_eq!(Some(&SUN), MOON.primary().and_then(Body::primary));
+        assert_eq!(None, find("Vulcan"));
+    }
+}
@@ -0,0 +1,5 @@
+scalar! {
+    struct Charge(f64) {
+        fn in_coulombs(coulombs) -> Self;
+    }
+}
@@ -0,0 +1,116 @@
+use crate::constants::SIGMA;
+use crate::{FluxDensity, Length, Power, Sqrt, Temperature, UnitInterval, AU, SOLAR_LUMINOSITY};
+
+impl Temperature {
+    /// Temperature at which a planet radiates away as much as it absorbs from its star,
+    /// averaged over a rapidly rotating sphere, or `None` for a body with zero emissivity,
+    /// which cannot radiate and so never reaches equilibrium
+    /// https://en.wikipedia.org/wiki/Planetary_equilibrium_temperature
+    pub fn equilibrium(
+        flux: FluxDensity,
+        albedo: UnitInterval,
+        emissivity: UnitInterval,
+    ) -> Option<Self> {
+        if emissivity.f64() <= 0.0 {
+            return None;
+        }
+
+        let absorbed = flux * (!albedo).f64();
+        let above_absolute_zero = (absorbed / (SIGMA.value * (4.0 * emissivity.f64())))
+            .sqrt()
+            .sqrt();
+        Some(Temperature::from_origin(above_absolute_zero))
+    }
+
+    /// Surface temperature under a single atmospheric layer that absorbs `greenhouse`
+    /// of the outgoing radiation, where about 0.78 turns Earth's 255 K into 288 K
+    /// https://en.wikipedia.org/wiki/Idealized_greenhouse_model
+    #[inline]
+    pub fn with_greenhouse(self, greenhouse: UnitInterval) -> Self {
+        let factor = (2.0 / (2.0 - greenhouse.f64())).powf(0.25);
+        Temperature::in_k(self.kelvin() * factor)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Habitability {
+    TooHot,
+    Habitable,
+    TooCold,
+}
+
+/// Orbital radii around a star where liquid water could exist on a planet's surface
+/// https://en.wikipedia.org/wiki/Circumstellar_habitable_zone
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HabitableZone {
+    pub inner: Length,
+    pub outer: Length,
+}
+
+impl HabitableZone {
+    pub fn of_star(luminosity: Power) -> Self {
+        let solar = luminosity / SOLAR_LUMINOSITY;
+
+        Self {
+            inner: AU * (solar / Self::INNER_FLUX).sqrt(),
+            outer: AU * (solar / Self::OUTER_FLUX).sqrt(),
+        }
+    }
+
+    pub fn classify(&self, orbit_radius: Length) -> Habitability {
+        if orbit_radius < self.inner {
+            Habitability::TooHot
+        } else if orbit_radius > self.outer {
+            Habitability::TooCold
+        } else {
+            Habitability::Habitable
+        }
+    }
+
+    #[inline]
+    pub fn contains(&self, orbit_radius: Length) -> bool {
+        self.classify(orbit_radius) == Habitability::Habitable
+    }
+
+    /// Insolation at the edges of the zone relative to Earth's
+    const INNER_FLUX: f64 = 1.1;
+
+    const OUTER_FLUX: f64 = 0.53;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::catalogue::{EARTH, MARS, SUN, VENUS};
+    use crate::MainSequence;
+
+    #[test]
+    fn earth_temperature() {
+        let flux = FluxDensity::in_orbit(SUN.temperature, SUN.radius, AU);
+        let equilibrium =
+            Temperature::equilibrium(flux, EARTH.albedo, UnitInterval::clamp(1.0)).unwrap();
+        let surface = equilibrium.with_greenhouse(UnitInterval::clamp(0.78));
+
+        assert!((equilibrium.kelvin() - 254.0).abs() < 1.0);
+        assert!((surface.kelvin() - EARTH.temperature.kelvin()).abs() < 1.0);
+
+        let grey = Temperature::equilibrium(flux, EARTH.albedo, UnitInterval::clamp(0.5));
+        assert!(grey.unwrap() > equilibrium);
+
+        let non_emitting = Temperature::equilibrium(flux, EARTH.albedo, UnitInterval::clamp(0.0));
+        assert_eq!(None, non_emitting);
+    }
+
+    #[test]
+    fn solar_habitable_zone() {
+        let zone = HabitableZone::of_star(MainSequence::new(SUN.mass).luminosity());
+        let classify =
+            |body: crate::catalogue::Body| zone.classify(body.semi_major_axis().unwrap());
+
+        assert!((zone.inner / AU - 0.953).abs() < 1e-3);
+        assert!((zone.outer / AU - 1.374).abs() < 1e-3);
+        assert_eq!(Habitability::TooHot, classify(VENUS));
+        assert_eq!(Habitability::Habitable, classify(EARTH));
+        assert_eq!(Habitability::TooCold, classify(MARS));
+    }
+}
@@ -0,0 +1,172 @@
+use crate::{Duration, StdDuration, TimeIndex, UnitInterval};
+
+/// Advances game time from wall-clock time in fixed steps.
+///
+/// Wall-clock time is scaled by the warp factor and added to an accumulator, which is drained
+/// one `step` at a time. The game time is always `start + ticks * step`, so it does not drift
+/// no matter how the frames are sliced.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SimClock {
+    start: TimeIndex,
+    step: Duration,
+    ticks: u64,
+    accumulator: Duration,
+    warp: f64,
+    paused: bool,
+    max_ticks: u32,
+}
+
+/// The result of advancing a `SimClock` by one frame
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Frame {
+    /// Number of fixed steps to simulate this frame
+    pub ticks: u32,
+    /// How far the clock is between the last step and the next, for rendering
+    pub alpha: UnitInterval,
+}
+
+impl SimClock {
+    pub const MIN_WARP: f64 = 1.0;
+    pub const MAX_WARP: f64 = 1e6;
+
+    /// Default limit on catch-up steps per frame
+    pub const MAX_TICKS: u32 = 8;
+
+    #[inline]
+    pub fn new(start: TimeIndex, step: Duration) -> Self {
+        debug_assert!(step > Duration::zero());
+
+        Self {
+            start,
+            step,
+            ticks: 0,
+            accumulator: Duration::zero(),
+            warp: Self::MIN_WARP,
+            paused: false,
+            max_ticks: Self::MAX_TICKS,
+        }
+    }
+
+    /// Limits the steps taken in one frame. Game time beyond the limit is dropped, so the
+    /// clock falls behind the warp factor instead of spiralling when the simulation is slow.
+    #[inline]
+    pub fn with_max_ticks(mut self, max_ticks: u32) -> Self {
+        debug_assert!(max_ticks > 0);
+        self.max_ticks = max_ticks;
+        self
+    }
+
+    /// The game time of the last completed step
+    #[inline]
+    pub fn time(&self) -> TimeIndex {
+        self.start + self.ticks as f64 * self.step
+    }
+
+    #[inline]
+    pub const fn step(&self) -> Duration {
+        self.step
+    }
+
+    /// Total steps taken since the start
+    #[inline]
+    pub const fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    #[inline]
+    pub const fn warp(&self) -> f64 {
+        self.warp
+    }
+
+    /// Sets the warp factor, clamped to `MIN_WARP..=MAX_WARP`
+    #[inline]
+    pub fn set_warp(&mut self, warp: f64) {
+        self.warp = warp.clamp(Self::MIN_WARP, Self::MAX_WARP);
+    }
+
+    #[inline]
+    pub const fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    #[inline]
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    #[inline]
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    /// Advances the clock by the wall-clock time since the last frame
+    pub fn advance(&mut self, elapsed: StdDuration) -> Frame {
+        if !self.paused {
+            self.accumulator += Duration::from(elapsed) * self.warp;
+        }
+
+        let available = (self.accumulator / self.step).floor();
+        let ticks = available.min(self.max_ticks as f64) as u32;
+
+        self.ticks += ticks as u64;
+        self.accumulator -= ticks as f64 * self.step;
+        if available > self.max_ticks as f64 {
+            self.accumulator = self.accumulator % self.step;
+        }
+
+        Frame {
+            ticks,
+            alpha: UnitInterval::clamp(self.accumulator / self.step),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{DAY, MIN, S};
+
+    #[test]
+    fn fixed_steps_and_alpha() {
+        let mut clock = SimClock::new(TimeIndex::default(), 0.25 * S);
+
+        let frame = clock.advance(StdDuration::from_millis(625));
+
+        assert_eq!(2, frame.ticks);
+        assert_eq!(0.5, frame.alpha.f64());
+        assert_eq!(TimeIndex::default() + 0.5 * S, clock.time());
+
+        let frame = clock.advance(StdDuration::from_millis(125));
+
+        assert_eq!(1, frame.ticks);
+        assert_eq!(0.0, frame.alpha.f64());
+    }
+
+    #[test]
+    fn pause_and_warp() {
+        let mut clock = SimClock::new(TimeIndex::default(), MIN);
+        clock.set_warp(60.0);
+
+        assert_eq!(1, clock.advance(StdDuration::from_secs(1)).ticks);
+
+        clock.pause();
+        assert_eq!(0, clock.advance(StdDuration::from_secs(10)).ticks);
+
+        clock.resume();
+        clock.set_warp(1e9);
+        assert_eq!(SimClock::MAX_WARP, clock.warp());
+    }
+
+    #[test]
+    fn catch_up_is_capped() {
+        let mut clock = SimClock::new(TimeIndex::default(), MIN).with_max_ticks(4);
+        clock.set_warp(SimClock::MAX_WARP);
+
+        let frame = clock.advance(StdDuration::from_secs(1));
+
+        assert_eq!(4, frame.ticks);
+        assert_eq!(4, clock.ticks());
+        assert!(clock.time() < TimeIndex::default() + DAY);
+        assert_eq!(0, clock.advance(StdDuration::default()).ticks);
+    }
+}
@@ -0,0 +1,117 @@
+//! Physical constants, typed, using the CODATA 2018 recommended values.
+//! https://physics.nist.gov/cuu/Constants/
+
+use crate::*;
+use std::ops::{Div, Mul};
+
+/// A measured value together with its standard uncertainty.
+/// Exact constants (those fixed by the SI definitions) have an uncertainty of zero.
+///
+/// Multiplying or dividing a `Measured` uses its value, but only with the constant on the
+/// left, as in `G * mass`; write `mass * G.value` when it has to go on the right.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Measured<T> {
+    pub value: T,
+    pub uncertainty: T,
+}
+
+impl<T> Measured<T> {
+    #[inline]
+    pub const fn new(value: T, uncertainty: T) -> Self {
+        Self { value, uncertainty }
+    }
+}
+
+impl<T: Div<T, Output = f64> + Copy> Measured<T> {
+    #[inline]
+    pub fn relative_uncertainty(&self) -> f64 {
+        self.uncertainty / self.value
+    }
+}
+
+impl<T: Mul<Rhs>, Rhs> Mul<Rhs> for Measured<T> {
+    type Output = T::Output;
+
+    #[inline]
+    fn mul(self, rhs: Rhs) -> Self::Output {
+        self.value.mul(rhs)
+    }
+}
+
+impl<T: Div<Rhs>, Rhs> Div<Rhs> for Measured<T> {
+    type Output = T::Output;
+
+    #[inline]
+    fn div(self, rhs: Rhs) -> Self::Output {
+        self.value.div(rhs)
+    }
+}
+
+/// Newtonian constant of gravitation
+pub const G: Measured<GravitationalConstant> = Measured::new(
+    GravitationalConstant::in_m3_per_kg_s2(6.674_30e-11),
+    GravitationalConstant::in_m3_per_kg_s2(0.000_15e-11),
+);
+
+/// Speed of light in vacuum
+pub const C: Measured<Speed> = Measured::new(Speed::C, Speed::zero());
+
+/// Molar gas constant
+pub const R: Measured<GasConstant> = Measured::new(GasConstant::R, GasConstant::zero());
+
+/// Boltzmann constant
+pub const K_B: Measured<EnergyPerTemperature> = Measured::new(
+    EnergyPerTemperature::in_j_per_k(1.380_649e-23),
+    EnergyPerTemperature::zero(),
+);
+
+/// Planck constant
+pub const H: Measured<Action> =
+    Measured::new(Action::in_joule_seconds(6.626_070_15e-34), Action::zero());
+
+/// Avogadro constant
+pub const N_A: Measured<AmountInv> =
+    Measured::new(AmountInv::in_mol_inv(6.022_140_76e23), AmountInv::zero());
+
+/// Stefan-Boltzmann constant
+/// https://en.wikipedia.org/wiki/Stefan%E2%80%93Boltzmann_law
+pub const SIGMA: Measured<StefanBoltzmannConstant> = Measured::new(
+    StefanBoltzmannConstant::in_w_per_m2_k4(5.670_374_419e-8),
+    StefanBoltzmannConstant::zero(),
+);
+
+/// Elementary charge
+pub const E: Measured<Charge> =
+    Measured::new(Charge::in_coulombs(1.602_176_634e-19), Charge::zero());
+
+/// Standard acceleration of gravity
+pub const G_0: Measured<Acceleration> =
+    Measured::new(Acceleration::in_m_per_s2(9.806_65), Acceleration::zero());
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn gravity_at_earth_surface() {
+        let mass = Mass::in_kg(5.972e24);
+        let radius = Length::in_m(6.371e6);
+
+        let g: Acceleration = G * mass / radius.squared();
+
+        assert!((g - G_0.value).abs() < Acceleration::in_m_per_s2(0.05));
+    }
+
+    #[test]
+    fn boltzmann_times_avogadro_is_gas_constant() {
+        let r: GasConstant = K_B * N_A.value;
+
+        assert!(((r - R.value) / R.value).abs() < 1e-9);
+    }
+
+    #[test]
+    fn relative_uncertainty() {
+        assert!((G.relative_uncertainty() - 2.2e-5).abs() < 1e-6);
+        assert_eq!(0.0, K_B.relative_uncertainty());
+    }
+}
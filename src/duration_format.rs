@@ -0,0 +1,316 @@
+use crate::{Duration, DAY, HR, MIN, S, YR};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+const WEEK: Duration = Duration::in_d(7.0);
+const MILLENNIUM: Duration = Duration::in_yr(1000.0);
+
+/// The number of most significant components shown by `CompactDuration` and `VerboseDuration`
+const MAX_COMPONENTS: usize = 3;
+
+const ADAPTIVE_UNITS: [(Duration, &str); 6] = [
+    (MILLENNIUM, "kyr"),
+    (YR, "yr"),
+    (DAY, "d"),
+    (HR, "hr"),
+    (MIN, "min"),
+    (S, "s"),
+];
+
+const COMPACT_UNITS: [(Duration, &str); 5] =
+    [(YR, "y"), (DAY, "d"), (HR, "h"), (MIN, "m"), (S, "s")];
+
+const VERBOSE_UNITS: [(Duration, &str, &str); 7] = [
+    (MILLENNIUM, "millennium", "millennia"),
+    (YR, "year", "years"),
+    (WEEK, "week", "weeks"),
+    (DAY, "day", "days"),
+    (HR, "hour", "hours"),
+    (MIN, "minute", "minutes"),
+    (S, "second", "seconds"),
+];
+
+impl Duration {
+    /// Formats as `2y 14d 3h`
+    #[inline]
+    pub fn compact(&self) -> CompactDuration {
+        CompactDuration(*self)
+    }
+
+    /// Formats as `1 year, 2 weeks`
+    #[inline]
+    pub fn verbose(&self) -> VerboseDuration {
+        VerboseDuration(*self)
+    }
+}
+
+/// Uses the largest unit that keeps the value at or above one, e.g. `3.5 d` or `-1.2 kyr`
+impl Display for Duration {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let magnitude = self.abs();
+        let (unit, symbol) = ADAPTIVE_UNITS
+            .iter()
+            .copied()
+            .find(|(unit, _)| magnitude >= *unit)
+            .unwrap_or((S, "s"));
+
+        write!(f, "{:.1} {}", *self / unit, symbol)
+    }
+}
+
+pub struct CompactDuration(Duration);
+
+impl Display for CompactDuration {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let components = split(self.0, COMPACT_UNITS.map(|(unit, _)| unit));
+        if components.is_empty() {
+            return write!(f, "0s");
+        }
+
+        if self.0 < Duration::zero() {
+            write!(f, "-")?;
+        }
+        for (n, (i, count)) in components.into_iter().enumerate() {
+            if n > 0 {
+                write!(f, " ")?;
+            }
+            write!(f, "{}{}", count, COMPACT_UNITS[i].1)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct VerboseDuration(Duration);
+
+impl Display for VerboseDuration {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        let components = split(self.0, VERBOSE_UNITS.map(|(unit, _, _)| unit));
+        if components.is_empty() {
+            return write!(f, "0 seconds");
+        }
+
+        if self.0 < Duration::zero() {
+            write!(f, "-")?;
+        }
+        for (n, (i, count)) in components.into_iter().enumerate() {
+            if n > 0 {
+                write!(f, ", ")?;
+            }
+            let (_, singular, plural) = VERBOSE_UNITS[i];
+            let name = if count == 1 { singular } else { plural };
+            write!(f, "{} {}", count, name)?;
+        }
+        Ok(())
+    }
+}
+
+/// Splits the magnitude, rounded to whole seconds, into the most significant non-zero
+/// `(unit index, count)` pairs. Smaller components are truncated.
+fn split(duration: Duration, units: impl IntoIterator<Item = Duration>) -> Vec<(usize, u64)> {
+    let mut remaining = (duration.abs() / S).round() as u64;
+
+    units
+        .into_iter()
+        .map(|unit| (unit / S) as u64)
+        .enumerate()
+        .filter_map(|(i, unit)| {
+            let count = remaining / unit;
+            remaining %= unit;
+            (count > 0).then_some((i, count))
+        })
+        .take(MAX_COMPONENTS)
+        .collect()
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum ParseDurationError {
+    Empty,
+    InvalidNumber(String),
+    MissingUnit,
+    UnknownUnit(String),
+}
+
+impl Display for ParseDurationError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty duration"),
+            Self::InvalidNumber(number) => write!(f, "invalid number: {:?}", number),
+            Self::MissingUnit => write!(f, "missing unit"),
+            Self::UnknownUnit(unit) => write!(f, "unknown unit: {:?}", unit),
+        }
+    }
+}
+
+impl std::error::Error for ParseDurationError {}
+
+/// Parses `90 min`, `3d 4h`, `1 year, 2 weeks` or an ISO-8601 duration such as `P1DT2H`.
+///
+/// A leading `-` negates the whole duration. ISO months are rejected since their length is
+/// ambiguous.
+impl FromStr for Duration {
+    type Err = ParseDurationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest.trim_start()),
+            None => (false, s),
+        };
+        if s.is_empty() {
+            return Err(ParseDurationError::Empty);
+        }
+
+        let duration = match s.strip_prefix('P') {
+            Some(iso) => parse_iso(iso)?,
+            None => parse_units(s)?,
+        };
+
+        Ok(if negative { -duration } else { duration })
+    }
+}
+
+fn parse_units(mut s: &str) -> Result<Duration, ParseDurationError> {
+    let mut total = Duration::zero();
+
+    loop {
+        s = s.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+        if s.is_empty() {
+            return Ok(total);
+        }
+
+        let (number, rest) = split_while(s, |c| c.is_ascii_digit() || c == '.');
+        let (unit, rest) = split_while(rest.trim_start(), char::is_alphabetic);
+
+        total += parse_number(number)? * unit_of(unit)?;
+        s = rest;
+    }
+}
+
+fn parse_iso(s: &str) -> Result<Duration, ParseDurationError> {
+    let (date, time) = s.split_once('T').unwrap_or((s, ""));
+    if date.is_empty() && time.is_empty() {
+        return Err(ParseDurationError::Empty);
+    }
+
+    let date = parse_iso_part(date, &[('Y', YR), ('W', WEEK), ('D', DAY)])?;
+    let time = parse_iso_part(time, &[('H', HR), ('M', MIN), ('S', S)])?;
+    Ok(date + time)
+}
+
+fn parse_iso_part(
+    mut s: &str,
+    designators: &[(char, Duration)],
+) -> Result<Duration, ParseDurationError> {
+    let mut total = Duration::zero();
+
+    while !s.is_empty() {
+        let (number, rest) = split_while(s, |c| c.is_ascii_digit() || c == '.' || c == ',');
+        let mut rest = rest.chars();
+
+        let designator = rest.next().ok_or(ParseDurationError::MissingUnit)?;
+        let unit = designators
+            .iter()
+            .find(|(d, _)| *d == designator)
+            .map(|(_, unit)| *unit)
+            .ok_or_else(|| ParseDurationError::UnknownUnit(designator.to_string()))?;
+
+        // ISO-8601 allows a decimal comma
+        total += parse_number(&number.replace(',', "."))? * unit;
+        s = rest.as_str();
+    }
+
+    Ok(total)
+}
+
+fn split_while(s: &str, predicate: impl Fn(char) -> bool) -> (&str, &str) {
+    s.split_at(s.find(|c: char| !predicate(c)).unwrap_or(s.len()))
+}
+
+fn parse_number(number: &str) -> Result<f64, ParseDurationError> {
+    number
+        .parse()
+        .map_err(|_| ParseDurationError::InvalidNumber(number.to_string()))
+}
+
+fn unit_of(unit: &str) -> Result<Duration, ParseDurationError> {
+    match unit.to_lowercase().as_str() {
+        "" => Err(ParseDurationError::MissingUnit),
+        "s" | "sec" | "secs" | "second" | "seconds" => Ok(S),
+        "m" | "min" | "mins" | "minute" | "minutes" => Ok(MIN),
+        "h" | "hr" | "hrs" | "hour" | "hours" => Ok(HR),
+        "d" | "day" | "days" => Ok(DAY),
+        "w" | "wk" | "wks" | "week" | "weeks" => Ok(WEEK),
+        "y" | "yr" | "yrs" | "year" | "years" => Ok(YR),
+        "kyr" | "millennium" | "millennia" => Ok(MILLENNIUM),
+        _ => Err(ParseDurationError::UnknownUnit(unit.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn adaptive_display() {
+        assert_eq!("0.0 s", Duration::zero().to_string());
+        assert_eq!("45.0 s", (45.0 * S).to_string());
+        assert_eq!("1.5 hr", (90.0 * MIN).to_string());
+        assert_eq!("3.5 d", (3.5 * DAY).to_string());
+        assert_eq!("-1.5 kyr", (-1500.0 * YR).to_string());
+    }
+
+    #[test]
+    fn compact_and_verbose() {
+        let duration = 2.0 * YR + 14.0 * DAY + 3.0 * HR + 20.0 * S;
+
+        assert_eq!("2y 14d 3h", duration.compact().to_string());
+        assert_eq!("-1h 30m", (-90.0 * MIN).compact().to_string());
+        assert_eq!("0s", (0.4 * S).compact().to_string());
+
+        assert_eq!("1 year, 2 weeks", (YR + 2.0 * WEEK).verbose().to_string());
+        assert_eq!(
+            "2 millennia, 1 day, 1 second",
+            (2.0 * MILLENNIUM + DAY + S).verbose().to_string()
+        );
+        assert_eq!("0 seconds", Duration::zero().verbose().to_string());
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Ok(90.0 * MIN), "90 min".parse());
+        assert_eq!(Ok(3.0 * DAY + 4.0 * HR), "3d 4h".parse());
+        assert_eq!(Ok(-(YR + 2.0 * WEEK)), "-1 year, 2 weeks".parse());
+        assert_eq!(Ok(1.5 * HR), "1.5hr".parse());
+
+        let duration = 2.0 * YR + 14.0 * DAY + 3.0 * HR;
+        assert_eq!(Ok(duration), duration.compact().to_string().parse());
+    }
+
+    #[test]
+    fn parse_iso() {
+        assert_eq!(Ok(DAY + 2.0 * HR), "P1DT2H".parse());
+        assert_eq!(Ok(YR + 3.0 * WEEK), "P1Y3W".parse());
+        assert_eq!(Ok(30.0 * MIN + 1.5 * S), "PT30M1,5S".parse());
+    }
+
+    #[test]
+    fn parse_errors() {
+        let parse = |s: &str| s.parse::<Duration>();
+
+        assert_eq!(Err(ParseDurationError::Empty), parse("  "));
+        assert_eq!(Err(ParseDurationError::Empty), parse("PT"));
+        assert_eq!(Err(ParseDurationError::MissingUnit), parse("90"));
+        assert_eq!(
+            Err(ParseDurationError::UnknownUnit("fortnights".to_string())),
+            parse("3 fortnights")
+        );
+        assert_eq!(
+            Err(ParseDurationError::UnknownUnit("M".to_string())),
+            parse("P1M")
+        );
+        assert_eq!(
+            Err(ParseDurationError::InvalidNumber("1.2.3".to_string())),
+            parse("1.2.3d")
+        );
+    }
+}
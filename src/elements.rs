@@ -0,0 +1,356 @@
+use crate::{
+    Angle, Cross, Dot, GravitationalParameter, Length, Orbit, Speed, Sqrt, TimeIndex, Vector3, M,
+};
+use std::f64::consts::{PI, TAU};
+
+/// Classical orbital elements of a two-body orbit in 3D.
+///
+/// The reference plane is the xy-plane and the reference direction is the x-axis.
+/// The orbit size is given by the semi-latus rectum so that parabolic orbits can be represented.
+/// https://en.wikipedia.org/wiki/Orbital_elements
+///
+/// Angles that are undefined are set to zero:
+/// * equatorial orbits have no ascending node, so the argument of periapsis is measured from the
+///   x-axis (the longitude of periapsis)
+/// * circular orbits have no periapsis, so the true anomaly is measured from the ascending node
+///   (the argument of latitude), or from the x-axis if the orbit is also equatorial
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct OrbitalElements {
+    pub semi_latus_rectum: Length,
+    /// Below one for ellipses, one for parabolas and above one for hyperbolas
+    pub eccentricity: f64,
+    /// In the range `0..=PI`, above `PI / 2` for retrograde orbits
+    pub inclination: Angle,
+    pub longitude_of_ascending_node: Angle,
+    pub argument_of_periapsis: Angle,
+    pub true_anomaly: Angle,
+}
+
+impl OrbitalElements {
+    /// Negative for hyperbolic orbits and infinite for parabolic orbits
+    #[inline]
+    pub fn semi_major_axis(&self) -> Length {
+        self.semi_latus_rectum / (1.0 - self.eccentricity * self.eccentricity)
+    }
+
+    #[inline]
+    pub fn is_equatorial(&self) -> bool {
+        self.inclination.sin().abs() < Self::TOLERANCE
+    }
+
+    #[inline]
+    pub fn is_circular(&self) -> bool {
+        self.eccentricity < Self::TOLERANCE
+    }
+
+    /// Calculates the elements from a position and velocity relative to the central body
+    pub fn from_state_vectors(
+        position: Vector3<Length>,
+        velocity: Vector3<Speed>,
+        mu: GravitationalParameter,
+    ) -> Self {
+        let mu = mu.value;
+        let r = position / M;
+        let v = velocity / Speed::in_m_per_s(1.0);
+
+        let h = r.cross(v);
+        let h_unit = h / h.magnitude();
+        let node = Vector3 {
+            x: -h.y,
+            y: h.x,
+            z: 0.0,
+        };
+        let e = (r * (v.magnitude_squared() - mu / r.magnitude()) - v * r.dot(v)) / mu;
+        let eccentricity = e.magnitude();
+
+        let inclination = Angle::acos(h_unit.z.clamp(-1.0, 1.0));
+        let equatorial = node.magnitude() < Self::TOLERANCE * h.magnitude();
+        let circular = eccentricity < Self::TOLERANCE;
+
+        let (reference, longitude_of_ascending_node) = if equatorial {
+            let x_axis = Vector3 {
+                x: 1.0,
+                y: 0.0,
+                z: 0.0,
+            };
+            (x_axis, Angle::zero())
+        } else {
+            (node, normalized(Angle::atan2(node.y, node.x)))
+        };
+
+        let (argument_of_periapsis, true_anomaly) = if circular {
+            (Angle::zero(), angle_between(reference, r, h_unit))
+        } else {
+            (
+                angle_between(reference, e, h_unit),
+                angle_between(e, r, h_unit),
+            )
+        };
+
+        Self {
+            semi_latus_rectum: h.magnitude_squared() / mu * M,
+            eccentricity,
+            inclination,
+            longitude_of_ascending_node,
+            argument_of_periapsis,
+            true_anomaly,
+        }
+    }
+
+    /// Position and velocity relative to the central body
+    pub fn state_vectors(&self, mu: GravitationalParameter) -> (Vector3<Length>, Vector3<Speed>) {
+        let (p, q) = self.perifocal_axes();
+        let (sin_nu, cos_nu) = self.true_anomaly.sin_cos();
+        let e = self.eccentricity;
+
+        let radius = self.semi_latus_rectum / (1.0 + e * cos_nu);
+        let speed = (mu / self.semi_latus_rectum).sqrt();
+
+        let position = (p * cos_nu + q * sin_nu) * radius;
+        let velocity = (q * (e + cos_nu) - p * sin_nu) * speed;
+        (position, velocity)
+    }
+
+    /// Mean anomaly for elliptic orbits, or its hyperbolic and parabolic equivalents
+    pub fn mean_anomaly(&self) -> Angle {
+        let e = self.eccentricity;
+        let half_tan = (self.true_anomaly.value / 2.0).tan();
+
+        if e < 1.0 - Self::TOLERANCE {
+            let eccentric = 2.0 * (((1.0 - e) / (1.0 + e)).sqrt() * half_tan).atan();
+            normalized(Angle::in_rad(eccentric - e * eccentric.sin()))
+        } else if e > 1.0 + Self::TOLERANCE {
+            let hyperbolic = 2.0 * (((e - 1.0) / (e + 1.0)).sqrt() * half_tan).atanh();
+            Angle::in_rad(e * hyperbolic.sinh() - hyperbolic)
+        } else {
+            Angle::in_rad(half_tan + half_tan.powi(3) / 3.0)
+        }
+    }
+
+    /// Sets the true anomaly from a mean anomaly by solving Kepler's equation
+    pub fn set_mean_anomaly(&mut self, mean_anomaly: Angle) {
+        let e = self.eccentricity;
+        let m = mean_anomaly.value;
+
+        let true_anomaly = if e < 1.0 - Self::TOLERANCE {
+            let m = m.rem_euclid(TAU);
+            let eccentric = newton(if e < 0.8 { m } else { PI }, |x| {
+                (x - e * x.sin() - m, 1.0 - e * x.cos())
+            });
+            2.0 * (((1.0 + e) / (1.0 - e)).sqrt() * (eccentric / 2.0).tan()).atan()
+        } else if e > 1.0 + Self::TOLERANCE {
+            let hyperbolic = newton((2.0 * m / e).asinh(), |x| {
+                (e * x.sinh() - x - m, e * x.cosh() - 1.0)
+            });
+            2.0 * (((e + 1.0) / (e - 1.0)).sqrt() * (hyperbolic / 2.0).tanh()).atan()
+        } else {
+            // Barker's equation
+            let w = (1.5 * m + (2.25 * m * m + 1.0).sqrt()).cbrt();
+            2.0 * (w - 1.0 / w).atan()
+        };
+
+        self.true_anomaly = normalized(Angle::in_rad(true_anomaly));
+    }
+
+    /// The unit vectors towards the periapsis and 90° ahead of it in the orbital plane
+    fn perifocal_axes(&self) -> (Vector3<f64>, Vector3<f64>) {
+        let (sin_o, cos_o) = self.longitude_of_ascending_node.sin_cos();
+        let (sin_w, cos_w) = self.argument_of_periapsis.sin_cos();
+        let (sin_i, cos_i) = self.inclination.sin_cos();
+
+        let p = Vector3 {
+            x: cos_o * cos_w - sin_o * sin_w * cos_i,
+            y: sin_o * cos_w + cos_o * sin_w * cos_i,
+            z: sin_w * sin_i,
+        };
+        let q = Vector3 {
+            x: -cos_o * sin_w - sin_o * cos_w * cos_i,
+            y: -sin_o * sin_w + cos_o * cos_w * cos_i,
+            z: cos_w * sin_i,
+        };
+        (p, q)
+    }
+
+    const TOLERANCE: f64 = 1e-11;
+}
+
+impl Orbit {
+    /// The orbit in the xy-plane with the body at its position at `time`
+    #[inline]
+    pub fn elements(&self, time: TimeIndex) -> OrbitalElements {
+        OrbitalElements {
+            semi_latus_rectum: self.semi_latus_rectum(),
+            eccentricity: self.eccentricity,
+            inclination: Angle::zero(),
+            longitude_of_ascending_node: Angle::zero(),
+            argument_of_periapsis: normalized(self.argument_of_periapsis),
+            true_anomaly: normalized(self.true_anomaly(time)),
+        }
+    }
+}
+
+/// Angle from `from` to `to` anticlockwise around `normal`, in the range `0..TAU`
+#[inline]
+fn angle_between(from: Vector3<f64>, to: Vector3<f64>, normal: Vector3<f64>) -> Angle {
+    normalized(Angle::atan2(from.cross(to).dot(normal), from.dot(to)))
+}
+
+#[inline]
+fn normalized(angle: Angle) -> Angle {
+    Angle::in_rad(angle.value.rem_euclid(TAU))
+}
+
+fn newton(mut x: f64, f: impl Fn(f64) -> (f64, f64)) -> f64 {
+    for _ in 0..50 {
+        let (value, derivative) = f(x);
+        let step = value / derivative;
+        x -= step;
+
+        if step.abs() < 1e-12 * x.abs().max(1.0) {
+            break;
+        }
+    }
+    x
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::catalogue::SUN;
+    use crate::constants::G;
+    use crate::{AU, DEG, KM};
+
+    fn mu() -> GravitationalParameter {
+        G * SUN.mass
+    }
+
+    fn assert_angle(expected: Angle, actual: Angle) {
+        let difference = (expected - actual).value.rem_euclid(TAU);
+        assert!(
+            difference.min(TAU - difference) < 1e-9,
+            "{:?} != {:?}",
+            expected,
+            actual
+        );
+    }
+
+    fn assert_elements(expected: OrbitalElements, actual: OrbitalElements) {
+        let p = expected.semi_latus_rectum / actual.semi_latus_rectum;
+        assert!((p - 1.0).abs() < 1e-9, "{:?} != {:?}", expected, actual);
+        assert!((expected.eccentricity - actual.eccentricity).abs() < 1e-9);
+        assert_angle(expected.inclination, actual.inclination);
+        assert_angle(
+            expected.longitude_of_ascending_node,
+            actual.longitude_of_ascending_node,
+        );
+        assert_angle(expected.argument_of_periapsis, actual.argument_of_periapsis);
+        assert_angle(expected.true_anomaly, actual.true_anomaly);
+    }
+
+    fn round_trip(elements: OrbitalElements) -> OrbitalElements {
+        let (position, velocity) = elements.state_vectors(mu());
+        OrbitalElements::from_state_vectors(position, velocity, mu())
+    }
+
+    #[test]
+    fn inclined_ellipse_round_trip() {
+        let elements = OrbitalElements {
+            semi_latus_rectum: 1.2 * AU,
+            eccentricity: 0.3,
+            inclination: 30.0 * DEG,
+            longitude_of_ascending_node: 60.0 * DEG,
+            argument_of_periapsis: 100.0 * DEG,
+            true_anomaly: 250.0 * DEG,
+        };
+
+        assert_elements(elements, round_trip(elements));
+        assert!((elements.semi_major_axis() - 1.2 * AU / 0.91).abs() < KM);
+    }
+
+    #[test]
+    fn hyperbola_round_trip() {
+        let elements = OrbitalElements {
+            semi_latus_rectum: 2.0 * AU,
+            eccentricity: 1.5,
+            inclination: 120.0 * DEG,
+            longitude_of_ascending_node: 10.0 * DEG,
+            argument_of_periapsis: 300.0 * DEG,
+            true_anomaly: 40.0 * DEG,
+        };
+
+        assert_elements(elements, round_trip(elements));
+        assert!(elements.semi_major_axis() < Length::zero());
+    }
+
+    #[test]
+    fn circular_and_equatorial_edge_cases() {
+        let radius = AU;
+        let speed = (mu() / radius).sqrt();
+        let position = Vector3::in_m(0.0, radius.value, 0.0);
+
+        let prograde = OrbitalElements::from_state_vectors(
+            position,
+            Vector3::in_m_per_s(-speed.value, 0.0, 0.0),
+            mu(),
+        );
+
+        assert!(prograde.is_circular() && prograde.is_equatorial());
+        assert_angle(Angle::zero(), prograde.inclination);
+        assert_angle(Angle::zero(), prograde.argument_of_periapsis);
+        assert_angle(90.0 * DEG, prograde.true_anomaly);
+
+        let retrograde = OrbitalElements::from_state_vectors(
+            position,
+            Vector3::in_m_per_s(speed.value, 0.0, 0.0),
+            mu(),
+        );
+
+        assert_angle(180.0 * DEG, retrograde.inclination);
+        assert_elements(retrograde, round_trip(retrograde));
+
+        let polar = OrbitalElements {
+            semi_latus_rectum: radius,
+            eccentricity: 0.0,
+            inclination: 90.0 * DEG,
+            longitude_of_ascending_node: 45.0 * DEG,
+            argument_of_periapsis: Angle::zero(),
+            true_anomaly: 30.0 * DEG,
+        };
+
+        assert_elements(polar, round_trip(polar));
+    }
+
+    #[test]
+    fn mean_anomaly_round_trip() {
+        for (eccentricity, true_anomaly) in
+            [(0.0, 1.0), (0.5, 4.0), (0.95, 3.0), (1.0, 2.0), (3.0, 1.2)]
+        {
+            let mut elements = OrbitalElements {
+                semi_latus_rectum: AU,
+                eccentricity,
+                inclination: Angle::zero(),
+                longitude_of_ascending_node: Angle::zero(),
+                argument_of_periapsis: Angle::zero(),
+                true_anomaly: Angle::in_rad(true_anomaly),
+            };
+
+            let mean_anomaly = elements.mean_anomaly();
+            elements.set_mean_anomaly(mean_anomaly);
+
+            assert_angle(Angle::in_rad(true_anomaly), elements.true_anomaly);
+        }
+    }
+
+    #[test]
+    fn planar_orbit_elements() {
+        let orbit = Orbit::new(AU, 0.2, 45.0 * DEG, TimeIndex::default(), SUN.mass);
+        let time = orbit.epoch + orbit.period() / 3.0;
+
+        let (position, _) = orbit.elements(time).state_vectors(mu());
+        let expected = orbit.position(time);
+
+        assert!((position.x - expected.x).abs() < KM);
+        assert!((position.y - expected.y).abs() < KM);
+        assert_eq!(Length::zero(), position.z);
+    }
+}
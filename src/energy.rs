@@ -1,4 +1,4 @@
-use crate::{Force, Length, Pressure, Volume};
+use crate::{Duration, Force, Frequency, Length, Mass, Pressure, SpeedSquared, Volume};
 
 pub const J: Energy = Energy::in_joules(1.0);
 
@@ -10,6 +10,16 @@ scalar! {
 
 scalar_div!(Energy | Length = Force);
 scalar_div!(Energy | Pressure = Volume);
+scalar_div!(Energy | Mass = SpeedSquared);
+
+scalar! {
+    struct Action(f64) {
+        fn in_joule_seconds(joule_seconds) -> Self;
+    }
+}
+
+scalar_div!(Action | Duration = Energy);
+scalar_div!(Energy | Frequency = Action);
 
 #[test]
 fn energy_conversion() {
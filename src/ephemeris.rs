@@ -0,0 +1,227 @@
+use crate::{Angle, AngularSpeed, Distance, Duration, Length, Mass, TimeIndex, Vector2};
+
+/// Positions of many bodies on circular orbits, stored as a structure of arrays so that
+/// updating every body for a new time is a single pass over contiguous data.
+///
+/// Positions are relative to each body's own central mass.
+#[derive(Debug, Clone, Default)]
+pub struct Ephemeris {
+    radius: Vec<Length>,
+    angular_speed: Vec<AngularSpeed>,
+    /// Angle from the positive x-axis at `TimeIndex::default()`
+    phase: Vec<Angle>,
+    x: Vec<Length>,
+    y: Vec<Length>,
+    time: TimeIndex,
+}
+
+impl Ephemeris {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            radius: Vec::with_capacity(capacity),
+            angular_speed: Vec::with_capacity(capacity),
+            phase: Vec::with_capacity(capacity),
+            x: Vec::with_capacity(capacity),
+            y: Vec::with_capacity(capacity),
+            time: TimeIndex::default(),
+        }
+    }
+
+    /// Adds a body at the current time, returning its index
+    pub fn push(&mut self, radius: Length, central_mass: Mass, phase: Angle) -> usize {
+        let angular_speed = AngularSpeed::of_orbit(central_mass, radius);
+        let position = Self::evaluate(radius, angular_speed, phase, Self::elapsed(self.time));
+
+        self.radius.push(radius);
+        self.angular_speed.push(angular_speed);
+        self.phase.push(phase);
+        self.x.push(position.x);
+        self.y.push(position.y);
+        self.radius.len() - 1
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.radius.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.radius.is_empty()
+    }
+
+    /// The time the cached positions are for
+    #[inline]
+    pub fn time(&self) -> TimeIndex {
+        self.time
+    }
+
+    /// Moves every body to its position at `time`
+    pub fn update(&mut self, time: TimeIndex) {
+        let elapsed = Self::elapsed(time);
+
+        let orbits = self.radius.iter().zip(&self.angular_speed).zip(&self.phase);
+        let positions = self.x.iter_mut().zip(self.y.iter_mut());
+
+        for (((radius, angular_speed), phase), (x, y)) in orbits.zip(positions) {
+            let position = Self::evaluate(*radius, *angular_speed, *phase, elapsed);
+            *x = position.x;
+            *y = position.y;
+        }
+
+        self.time = time;
+    }
+
+    #[inline]
+    pub fn position(&self, index: usize) -> Distance {
+        Vector2 {
+            x: self.x[index],
+            y: self.y[index],
+        }
+    }
+
+    #[inline]
+    pub fn positions(&self) -> impl Iterator<Item = Distance> + '_ {
+        self.x
+            .iter()
+            .zip(&self.y)
+            .map(|(x, y)| Vector2 { x: *x, y: *y })
+    }
+
+    #[inline]
+    pub fn xs(&self) -> &[Length] {
+        &self.x
+    }
+
+    #[inline]
+    pub fn ys(&self) -> &[Length] {
+        &self.y
+    }
+
+    /// Position of one body at any time, leaving the cached positions untouched
+    #[inline]
+    pub fn position_at(&self, index: usize, time: TimeIndex) -> Distance {
+        Self::evaluate(
+            self.radius[index],
+            self.angular_speed[index],
+            self.phase[index],
+            Self::elapsed(time),
+        )
+    }
+
+    /// Positions of every body at any time, leaving the cached positions untouched
+    pub fn positions_at(&self, time: TimeIndex) -> Vec<Distance> {
+        let elapsed = Self::elapsed(time);
+
+        self.radius
+            .iter()
+            .zip(&self.angular_speed)
+            .zip(&self.phase)
+            .map(|((radius, angular_speed), phase)| {
+                Self::evaluate(*radius, *angular_speed, *phase, elapsed)
+            })
+            .collect()
+    }
+
+    /// Positions of one body at `count` times spaced by `step`, starting at `start`
+    pub fn path(
+        &self,
+        index: usize,
+        start: TimeIndex,
+        step: Duration,
+        count: usize,
+    ) -> Vec<Distance> {
+        (0..count)
+            .map(|i| self.position_at(index, start + step * i as f64))
+            .collect()
+    }
+
+    #[inline]
+    fn elapsed(time: TimeIndex) -> Duration {
+        time - TimeIndex::default()
+    }
+
+    #[inline]
+    fn evaluate(
+        radius: Length,
+        angular_speed: AngularSpeed,
+        phase: Angle,
+        elapsed: Duration,
+    ) -> Distance {
+        Vector2::from_angle_and_magnitude(phase + angular_speed * elapsed, radius)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::catalogue::SUN;
+    use crate::{AU, DEG};
+
+    fn ephemeris() -> Ephemeris {
+        let mut ephemeris = Ephemeris::with_capacity(100);
+        for i in 0..100 {
+            let i = i as f64;
+            ephemeris.push(AU * (0.5 + i / 10.0), SUN.mass, i * 7.0 * DEG);
+        }
+        ephemeris
+    }
+
+    #[test]
+    fn update_matches_orbit_helpers() {
+        let mut ephemeris = ephemeris();
+        let time = TimeIndex::default() + Duration::in_d(123.0);
+        ephemeris.update(time);
+
+        assert_eq!(time, ephemeris.time());
+        assert_eq!(100, ephemeris.positions().count());
+
+        for i in [0, 42, 99] {
+            let radius = AU * (0.5 + i as f64 / 10.0);
+            let angle = i as f64 * 7.0 * DEG
+                + AngularSpeed::of_orbit(SUN.mass, radius) * Duration::in_d(123.0);
+
+            assert_eq!(
+                Vector2::from_angle_and_magnitude(angle, radius),
+                ephemeris.position(i)
+            );
+            assert_eq!(ephemeris.position(i).x, ephemeris.xs()[i]);
+        }
+    }
+
+    #[test]
+    fn future_queries_leave_cache() {
+        let mut ephemeris = ephemeris();
+        let now = TimeIndex::default() + Duration::in_d(10.0);
+        let later = now + Duration::in_d(30.0);
+        ephemeris.update(now);
+
+        let cached = ephemeris.positions().collect::<Vec<_>>();
+        let future = ephemeris.positions_at(later);
+
+        assert_eq!(cached, ephemeris.positions().collect::<Vec<_>>());
+
+        ephemeris.update(later);
+        assert_eq!(future, ephemeris.positions().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn path_preview() {
+        let ephemeris = ephemeris();
+        let start = TimeIndex::default() + Duration::in_d(5.0);
+        let path = ephemeris.path(7, start, Duration::in_d(1.0), 10);
+
+        assert_eq!(10, path.len());
+        assert_eq!(ephemeris.position_at(7, start), path[0]);
+        assert_eq!(
+            ephemeris.position_at(7, start + Duration::in_d(9.0)),
+            path[9]
+        );
+    }
+}
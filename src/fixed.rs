@@ -0,0 +1,744 @@
+//! Fixed-point backing for quantities that must give bit-identical results on every machine,
+//! such as those simulated in lockstep multiplayer.
+//!
+//! `Fixed` can be used as the base type in `scalar!`. All of its arithmetic, including `sqrt`
+//! and the trig functions on `FixedAngle`, is done with integer operations.
+
+use crate::{Angle, Length, Norm, Sqrt, Squared, Vector2, Zero};
+use std::fmt::{Display, Formatter};
+use std::ops::*;
+
+/// A signed fixed-point number with 64 integer bits and 64 fractional bits (I64F64),
+/// which covers distances of several hundred light years in meters.
+///
+/// Arithmetic saturates at `MIN` and `MAX` instead of overflowing, and division by zero
+/// panics as it does for integers.
+#[repr(transparent)]
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Fixed(i128);
+
+impl Fixed {
+    #[inline]
+    pub const fn from_bits(bits: i128) -> Self {
+        Self(bits)
+    }
+
+    #[inline]
+    pub const fn to_bits(self) -> i128 {
+        self.0
+    }
+
+    #[inline]
+    pub const fn from_int(value: i64) -> Self {
+        Self((value as i128) << Self::FRAC_BITS)
+    }
+
+    /// Rounds to the nearest representable value, saturating at `MIN` and `MAX`
+    #[inline]
+    pub fn from_f64(value: f64) -> Self {
+        Self((value * Self::ONE_F64).round() as i128)
+    }
+
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / Self::ONE_F64
+    }
+
+    #[inline]
+    pub const fn is_finite(self) -> bool {
+        true
+    }
+
+    #[inline]
+    pub const fn abs(self) -> Self {
+        Self(self.0.saturating_abs())
+    }
+
+    /// Product rounded toward zero, or `None` if it overflows
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        let (a, b) = (self.0.unsigned_abs(), rhs.0.unsigned_abs());
+        let (a_high, a_low) = (a >> Self::FRAC_BITS, a & Self::LOW_BITS);
+        let (b_high, b_low) = (b >> Self::FRAC_BITS, b & Self::LOW_BITS);
+
+        // the 256-bit product shifted right by FRAC_BITS, assembled from 64-bit halves
+        let high = a_high * b_high;
+        if high >> Self::FRAC_BITS != 0 {
+            return None;
+        }
+
+        let middle = match (a_high * b_low).checked_add(a_low * b_high) {
+            Some(middle) => middle,
+            None => return None,
+        };
+
+        let magnitude = match (high << Self::FRAC_BITS).checked_add(middle) {
+            Some(sum) => sum.checked_add((a_low * b_low) >> Self::FRAC_BITS),
+            None => None,
+        };
+
+        match magnitude {
+            Some(magnitude) => Self::from_magnitude(magnitude, (self.0 < 0) != (rhs.0 < 0)),
+            None => None,
+        }
+    }
+
+    /// Quotient rounded toward zero, or `None` if it overflows or `rhs` is zero
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.0 == 0 {
+            return None;
+        }
+
+        let (a, b) = (self.0.unsigned_abs(), rhs.0.unsigned_abs());
+        let whole = a / b;
+        if whole >> Self::FRAC_BITS != 0 {
+            return None;
+        }
+
+        let mut remainder = a % b;
+        let fraction = if remainder >> Self::FRAC_BITS == 0 {
+            (remainder << Self::FRAC_BITS) / b
+        } else {
+            // long division, one bit at a time; remainder < b <= 2^127 so doubling can't overflow
+            let mut fraction = 0;
+            let mut i = 0;
+            while i < Self::FRAC_BITS {
+                remainder <<= 1;
+                fraction <<= 1;
+                if remainder >= b {
+                    remainder -= b;
+                    fraction |= 1;
+                }
+                i += 1;
+            }
+            fraction
+        };
+
+        Self::from_magnitude(
+            (whole << Self::FRAC_BITS) | fraction,
+            (self.0 < 0) != (rhs.0 < 0),
+        )
+    }
+
+    /// Square root, rounded down. Negative values return zero.
+    pub fn sqrt(self) -> Self {
+        if self.0 <= 0 {
+            return Self::ZERO;
+        }
+
+        // the root of `bits << FRAC_BITS` can need 192 bits, so shift as far as u128 allows
+        // by an even amount and apply the rest of the shift to the root
+        let bits = self.0 as u128;
+        let shift = (bits.leading_zeros() & !1).min(Self::FRAC_BITS);
+        let root = isqrt(bits << shift) << ((Self::FRAC_BITS - shift) / 2);
+
+        Self(root as i128)
+    }
+
+    #[inline]
+    const fn from_magnitude(magnitude: u128, negative: bool) -> Option<Self> {
+        if negative {
+            if magnitude <= i128::MIN.unsigned_abs() {
+                Some(Self((magnitude as i128).wrapping_neg()))
+            } else {
+                None
+            }
+        } else if magnitude <= i128::MAX as u128 {
+            Some(Self(magnitude as i128))
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    const fn saturated(negative: bool) -> Self {
+        if negative {
+            Self::MIN
+        } else {
+            Self::MAX
+        }
+    }
+
+    const FRAC_BITS: u32 = 64;
+
+    const LOW_BITS: u128 = (1 << Self::FRAC_BITS) - 1;
+
+    const ONE_F64: f64 = (1u128 << Self::FRAC_BITS) as f64;
+
+    pub const ZERO: Self = Self(0);
+
+    pub const ONE: Self = Self(1 << Self::FRAC_BITS);
+
+    pub const EPSILON: Self = Self(1);
+
+    pub const MIN: Self = Self(i128::MIN);
+
+    pub const MAX: Self = Self(i128::MAX);
+
+    pub const PI: Self = Self(57_952_155_664_616_982_739);
+
+    pub const TAU: Self = Self(115_904_311_329_233_965_478);
+
+    pub const FRAC_PI_2: Self = Self(28_976_077_832_308_491_370);
+}
+
+impl Zero for Fixed {
+    const ZERO: Self = Fixed::ZERO;
+}
+
+impl Sqrt for Fixed {
+    type Output = Self;
+    #[inline]
+    fn sqrt(self) -> Self {
+        Fixed::sqrt(self)
+    }
+}
+
+impl const Squared for Fixed {
+    type Output = Self;
+    #[inline]
+    fn squared(self) -> Self {
+        self * self
+    }
+}
+
+impl Norm for Fixed {
+    /// Divides through by the largest component first, so that squaring can't overflow
+    fn norm<const N: usize>(components: [Self; N]) -> Self {
+        let max = components
+            .iter()
+            .map(|c| c.abs())
+            .max()
+            .unwrap_or(Self::ZERO);
+        if max == Self::ZERO {
+            return Self::ZERO;
+        }
+
+        let sum = components
+            .iter()
+            .map(|c| (*c / max).squared())
+            .sum::<Fixed>();
+        max * sum.sqrt()
+    }
+}
+
+impl From<i32> for Fixed {
+    #[inline]
+    fn from(value: i32) -> Self {
+        Self::from_int(value as i64)
+    }
+}
+
+impl From<i64> for Fixed {
+    #[inline]
+    fn from(value: i64) -> Self {
+        Self::from_int(value)
+    }
+}
+
+impl From<Fixed> for f64 {
+    #[inline]
+    fn from(value: Fixed) -> Self {
+        value.to_f64()
+    }
+}
+
+impl Display for Fixed {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        Display::fmt(&self.to_f64(), f)
+    }
+}
+
+impl const Add for Fixed {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+}
+
+impl AddAssign for Fixed {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl const Sub for Fixed {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+}
+
+impl SubAssign for Fixed {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl const Mul for Fixed {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        match self.checked_mul(rhs) {
+            Some(product) => product,
+            None => Self::saturated((self.0 < 0) != (rhs.0 < 0)),
+        }
+    }
+}
+
+impl MulAssign for Fixed {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl MulAssign<&Fixed> for Fixed {
+    #[inline]
+    fn mul_assign(&mut self, rhs: &Self) {
+        *self = *self * *rhs;
+    }
+}
+
+impl const Div for Fixed {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        if rhs.0 == 0 {
+            panic!("attempt to divide by zero");
+        }
+
+        match self.checked_div(rhs) {
+            Some(quotient) => quotient,
+            None => Self::saturated((self.0 < 0) != (rhs.0 < 0)),
+        }
+    }
+}
+
+impl DivAssign for Fixed {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+impl DivAssign<&Fixed> for Fixed {
+    #[inline]
+    fn div_assign(&mut self, rhs: &Self) {
+        *self = *self / *rhs;
+    }
+}
+
+impl const Rem for Fixed {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: Self) -> Self {
+        Self(self.0 % rhs.0)
+    }
+}
+
+impl const Neg for Fixed {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self(self.0.saturating_neg())
+    }
+}
+
+impl std::iter::Sum for Fixed {
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+scalar! {
+    struct FixedAngle(Fixed) {
+        fn in_fixed_rad(radians) -> Self;
+    }
+}
+
+scalar! {
+    struct FixedLength(Fixed) {
+        fn in_fixed_m(meters) -> Self;
+    }
+}
+
+impl FixedAngle {
+    /// Returns `(sin, cos)`, accurate to about 1e-9
+    pub fn sin_cos(self) -> (Fixed, Fixed) {
+        // reduce to -PI..PI, then to -PI/2..PI/2 where CORDIC converges
+        let mut angle = self.value % Fixed::TAU;
+        if angle > Fixed::PI {
+            angle -= Fixed::TAU;
+        } else if angle < -Fixed::PI {
+            angle += Fixed::TAU;
+        }
+
+        let flip = if angle > Fixed::FRAC_PI_2 {
+            angle -= Fixed::PI;
+            true
+        } else if angle < -Fixed::FRAC_PI_2 {
+            angle += Fixed::PI;
+            true
+        } else {
+            false
+        };
+
+        let mut x = CORDIC_GAIN;
+        let mut y = 0i128;
+        let mut z = angle.0;
+
+        for (i, atan) in ATAN_TABLE.iter().enumerate() {
+            let (dx, dy) = (y >> i, x >> i);
+            if z >= 0 {
+                x -= dx;
+                y += dy;
+                z -= atan;
+            } else {
+                x += dx;
+                y -= dy;
+                z += atan;
+            }
+        }
+
+        if flip {
+            (Fixed(-y), Fixed(-x))
+        } else {
+            (Fixed(y), Fixed(x))
+        }
+    }
+
+    #[inline]
+    pub fn sin(self) -> Fixed {
+        self.sin_cos().0
+    }
+
+    #[inline]
+    pub fn cos(self) -> Fixed {
+        self.sin_cos().1
+    }
+
+    /// Four-quadrant arctangent of `y / x`, in the range `-PI..=PI`
+    pub fn atan2(y: Fixed, x: Fixed) -> Self {
+        if x == Fixed::ZERO && y == Fixed::ZERO {
+            return Self::zero();
+        }
+
+        // rotate into the right half-plane, where CORDIC converges
+        let (mut x, mut y, mut z) = if x.0 < 0 {
+            if y.0 >= 0 {
+                (y.0, x.0.saturating_neg(), Fixed::FRAC_PI_2.0)
+            } else {
+                (y.0.saturating_neg(), x.0, -Fixed::FRAC_PI_2.0)
+            }
+        } else {
+            (x.0, y.0, 0)
+        };
+
+        // scale down to avoid overflow, the angle only depends on the ratio
+        while x.unsigned_abs() > (i128::MAX >> 2) as u128
+            || y.unsigned_abs() > (i128::MAX >> 2) as u128
+        {
+            x >>= 1;
+            y >>= 1;
+        }
+
+        for (i, atan) in ATAN_TABLE.iter().enumerate() {
+            let (dx, dy) = (y >> i, x >> i);
+            if y < 0 {
+                x -= dx;
+                y += dy;
+                z -= atan;
+            } else {
+                x += dx;
+                y -= dy;
+                z += atan;
+            }
+        }
+
+        Self::new(Fixed(z))
+    }
+
+    pub const PI: Self = Self::in_fixed_rad(Fixed::PI);
+
+    pub const TAU: Self = Self::in_fixed_rad(Fixed::TAU);
+}
+
+impl From<Angle> for FixedAngle {
+    #[inline]
+    fn from(angle: Angle) -> Self {
+        Self::new(Fixed::from_f64(angle.value))
+    }
+}
+
+impl From<FixedAngle> for Angle {
+    #[inline]
+    fn from(angle: FixedAngle) -> Self {
+        Angle::in_rad(angle.value.to_f64())
+    }
+}
+
+impl From<Length> for FixedLength {
+    #[inline]
+    fn from(length: Length) -> Self {
+        Self::new(Fixed::from_f64(length.value))
+    }
+}
+
+impl From<FixedLength> for Length {
+    #[inline]
+    fn from(length: FixedLength) -> Self {
+        Length::in_m(length.value.to_f64())
+    }
+}
+
+impl<T> Vector2<T>
+where
+    T: Copy + Mul<Fixed, Output = T>,
+{
+    /// Deterministic equivalent of `from_angle_and_magnitude`
+    #[inline]
+    pub fn from_fixed_angle_and_magnitude(angle: FixedAngle, magnitude: T) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            x: magnitude * cos,
+            y: magnitude * sin,
+        }
+    }
+}
+
+/// Integer square root, rounded down
+fn isqrt(n: u128) -> u128 {
+    if n == 0 {
+        return 0;
+    }
+
+    // Newton's method from an initial guess above the root, which decreases monotonically
+    let bits = 128 - n.leading_zeros();
+    let mut x = 1u128 << ((bits | 1) / 2 + 1);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+/// 1 / product(sqrt(1 + 2^-2i))
+const CORDIC_GAIN: i128 = 11_201_839_480_117_811_816;
+
+/// atan(2^-i)
+const ATAN_TABLE: [i128; 64] = [
+    14_488_038_916_154_245_685,
+    8_552_788_783_625_223_587,
+    4_519_058_702_220_769_989,
+    2_293_944_758_691_655_110,
+    1_151_423_813_380_928_739,
+    576_273_212_193_474_858,
+    288_206_923_339_027_287,
+    144_112_256_152_217_971,
+    72_057_227_537_407_417,
+    36_028_751_206_084_335,
+    18_014_392_782_862_199,
+    9_007_198_538_913_212,
+    4_503_599_537_892_014,
+    2_251_799_802_500_437,
+    1_125_899_905_444_523,
+    562_949_953_246_549,
+    281_474_976_688_811,
+    140_737_488_352_597,
+    70_368_744_177_323,
+    35_184_372_088_789,
+    17_592_186_044_411,
+    8_796_093_022_207,
+    4_398_046_511_104,
+    2_199_023_255_552,
+    1_099_511_627_776,
+    549_755_813_888,
+    274_877_906_944,
+    137_438_953_472,
+    68_719_476_736,
+    34_359_738_368,
+    17_179_869_184,
+    8_589_934_592,
+    4_294_967_296,
+    2_147_483_648,
+    1_073_741_824,
+    536_870_912,
+    268_435_456,
+    134_217_728,
+    67_108_864,
+    33_554_432,
+    16_777_216,
+    8_388_608,
+    4_194_304,
+    2_097_152,
+    1_048_576,
+    524_288,
+    262_144,
+    131_072,
+    65_536,
+    32_768,
+    16_384,
+    8_192,
+    4_096,
+    2_048,
+    1_024,
+    512,
+    256,
+    128,
+    64,
+    32,
+    16,
+    8,
+    4,
+    2,
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{AU, LY};
+    use std::f64::consts::PI;
+
+    fn assert_close(expected: f64, actual: Fixed) {
+        assert!(
+            (expected - actual.to_f64()).abs() < 1e-12,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = Fixed::from_f64(2.5);
+        let b = Fixed::from_int(-4);
+
+        assert_eq!(Fixed::from_f64(-1.5), a + b);
+        assert_eq!(Fixed::from_f64(6.5), a - b);
+        assert_eq!(Fixed::from_int(-10), a * b);
+        assert_eq!(Fixed::from_f64(-0.625), a / b);
+    }
+
+    #[test]
+    fn arithmetic_saturates() {
+        let big = Fixed::from_int(i64::MAX);
+
+        assert_eq!(Fixed::MAX, big * big);
+        assert_eq!(Fixed::MIN, -big * big);
+        assert_eq!(Fixed::MAX, big / Fixed::EPSILON);
+        assert_eq!(Fixed::MAX, Fixed::MAX + Fixed::ONE);
+        assert_eq!(None, big.checked_mul(big));
+        assert_eq!(None, Fixed::ONE.checked_div(Fixed::ZERO));
+    }
+
+    #[test]
+    fn large_values_keep_precision() {
+        let au = Fixed::from_f64(AU.value);
+        let product = au * Fixed::from_f64(0.5);
+
+        assert_eq!(AU.value / 2.0, product.to_f64());
+        assert_eq!(Fixed::from_f64(0.5), product / au);
+        assert_eq!(Fixed::from_f64(-2.0), -au / product);
+    }
+
+    #[test]
+    fn sqrt() {
+        assert_eq!(Fixed::from_int(3), Fixed::from_int(9).sqrt());
+        assert_eq!(Fixed::ZERO, Fixed::ZERO.sqrt());
+        assert_close(2f64.sqrt(), Fixed::from_int(2).sqrt());
+        assert_close(0.001f64.sqrt(), Fixed::from_f64(0.001).sqrt());
+        assert_close(1e15f64.sqrt(), Fixed::from_f64(1e15).sqrt());
+        assert_eq!(Fixed::ZERO, Fixed::from_int(-4).sqrt());
+    }
+
+    #[test]
+    fn sin_cos() {
+        for i in -40..=40 {
+            let angle = Angle::in_rad(i as f64 * 0.2);
+            let (sin, cos) = FixedAngle::from(angle).sin_cos();
+
+            assert_close(angle.sin(), sin);
+            assert_close(angle.cos(), cos);
+        }
+    }
+
+    #[test]
+    fn atan2() {
+        for i in -15..=15 {
+            let angle = Angle::in_rad(i as f64 * 0.2);
+            let (y, x) = angle.sin_cos();
+
+            let actual = FixedAngle::atan2(Fixed::from_f64(3.0 * y), Fixed::from_f64(3.0 * x));
+
+            assert_close(angle.value, actual.value);
+        }
+
+        let quarter = FixedAngle::in_fixed_rad(Fixed::FRAC_PI_2);
+        assert_close(
+            3.0 * PI / 4.0,
+            FixedAngle::atan2(Fixed::MAX, Fixed::MIN).value,
+        );
+        assert_close(
+            -3.0 * PI / 4.0,
+            FixedAngle::atan2(Fixed::MIN, Fixed::MIN).value,
+        );
+        assert_close(PI, FixedAngle::atan2(Fixed::ZERO, Fixed::MIN).value);
+        assert_eq!(quarter, FixedAngle::atan2(Fixed::MAX, Fixed::ZERO));
+    }
+
+    #[test]
+    fn fixed_scalar_vector() {
+        let v = Vector2 {
+            x: FixedLength::in_fixed_m(Fixed::from_int(3)),
+            y: FixedLength::in_fixed_m(Fixed::from_int(4)),
+        };
+
+        assert_eq!(FixedLength::in_fixed_m(Fixed::from_int(5)), v.magnitude());
+        assert_eq!(Length::in_m(5.0), v.magnitude().into());
+    }
+
+    #[test]
+    fn magnitude_at_game_distances() {
+        for meters in [60e3, 1e9, AU.value, 100.0 * LY.value] {
+            let v = Vector2::in_m(3.0 * meters, -4.0 * meters);
+            let fixed = Vector2 {
+                x: FixedLength::from(v.x),
+                y: FixedLength::from(v.y),
+            };
+
+            let magnitude = Length::from(fixed.magnitude());
+            assert!((magnitude / v.magnitude() - 1.0).abs() < 1e-15);
+        }
+    }
+
+    #[test]
+    fn fixed_unit_vector() {
+        let vector = |x: Fixed, y: Fixed| Vector2 {
+            x: FixedLength::in_fixed_m(x),
+            y: FixedLength::in_fixed_m(y),
+        };
+
+        assert_eq!(None, vector(Fixed::ZERO, Fixed::ZERO).unit_vector());
+
+        let unit = vector(Fixed::from_int(3), Fixed::from_int(4))
+            .unit_vector()
+            .unwrap();
+        assert_close(0.6, unit.x);
+        assert_close(0.8, unit.y);
+
+        let au = Fixed::from_f64(AU.value);
+        let unit = vector(-au, au).unit_vector().unwrap();
+        assert_close(-0.5f64.sqrt(), unit.x);
+        assert_close(0.5f64.sqrt(), unit.y);
+    }
+}
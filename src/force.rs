@@ -1,4 +1,4 @@
-use crate::{Acceleration, Mass};
+use crate::{Acceleration, Duration, Mass, Speed};
 
 pub const N: Force = Force::in_newtons(1.0);
 
@@ -10,6 +10,15 @@ scalar! {
 
 scalar_div!(Force | Acceleration = Mass);
 
+scalar! {
+    struct Momentum(f64) {
+        fn in_kg_m_per_s(kilogram_meters_per_second) -> Self;
+    }
+}
+
+scalar_div!(Momentum | Mass = Speed);
+scalar_div!(Momentum | Duration = Force);
+
 #[test]
 fn force_conversion() {
     use crate::{KG, M, S};
@@ -0,0 +1,129 @@
+use crate::constants::G;
+use crate::{Acceleration, Area, Energy, FrequencySquared, Length, Mass, Speed, Sqrt, Volume};
+
+scalar! {
+    struct GravitationalConstant(f64) {
+        fn in_m3_per_kg_s2(meters_cubed_per_kilogram_second_squared) -> Self;
+    }
+}
+
+// The product of the gravitational constant and the mass of a body, μ = GM
+// https://en.wikipedia.org/wiki/Standard_gravitational_parameter
+scalar! {
+    struct GravitationalParameter(f64) {
+        fn in_m3_per_s2(meters_cubed_per_second_squared) -> Self;
+    }
+}
+
+scalar_div!(GravitationalParameter | Mass = GravitationalConstant);
+scalar_div!(GravitationalParameter | Area = Acceleration);
+scalar_div!(GravitationalParameter | Volume = FrequencySquared);
+
+impl Speed {
+    #[inline]
+    pub fn of_circular_orbit(mass: Mass, radius: Length) -> Self {
+        (G * mass / radius).sqrt()
+    }
+
+    /// Speed at `radius` on an orbit with the given semi-major axis, from the vis-viva equation
+    /// https://en.wikipedia.org/wiki/Vis-viva_equation
+    #[inline]
+    pub fn of_orbit_at(mass: Mass, radius: Length, semi_major_axis: Length) -> Self {
+        let mu = G * mass;
+        (mu / radius * 2.0 - mu / semi_major_axis).sqrt()
+    }
+
+    /// https://en.wikipedia.org/wiki/Escape_velocity
+    #[inline]
+    pub fn of_escape(mass: Mass, radius: Length) -> Self {
+        (G * mass / radius * 2.0).sqrt()
+    }
+}
+
+impl Acceleration {
+    #[inline]
+    pub fn of_surface(mass: Mass, radius: Length) -> Self {
+        Self::from_gravity(mass, radius)
+    }
+}
+
+impl Length {
+    /// Radius within which the body, rather than its primary, dominates the motion of a spacecraft
+    /// https://en.wikipedia.org/wiki/Sphere_of_influence_(astrodynamics)
+    #[inline]
+    pub fn sphere_of_influence(semi_major_axis: Length, mass: Mass, primary_mass: Mass) -> Self {
+        semi_major_axis * (mass / primary_mass).powf(0.4)
+    }
+
+    /// Radius within which the body can hold on to its own satellites
+    /// https://en.wikipedia.org/wiki/Hill_sphere
+    #[inline]
+    pub fn hill_sphere(
+        semi_major_axis: Length,
+        eccentricity: f64,
+        mass: Mass,
+        primary_mass: Mass,
+    ) -> Self {
+        semi_major_axis * (1.0 - eccentricity) * (mass / (primary_mass * 3.0)).cbrt()
+    }
+
+    /// Closest a rigid satellite can orbit before tides pull it apart
+    /// https://en.wikipedia.org/wiki/Roche_limit
+    #[inline]
+    pub fn roche_limit(primary_mass: Mass, satellite_mass: Mass, satellite_radius: Length) -> Self {
+        satellite_radius * (primary_mass / satellite_mass * 2.0).cbrt()
+    }
+}
+
+impl Energy {
+    /// Gravitational potential energy of two masses, zero when they are infinitely far apart
+    #[inline]
+    pub fn of_gravity(a: Mass, b: Mass, distance: Length) -> Self {
+        -(G * a / distance * b)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::catalogue::{EARTH, MOON, SUN};
+    use crate::{Squared, AU, KG, KM};
+
+    #[test]
+    fn escape_is_root_two_of_circular() {
+        let circular = Speed::of_circular_orbit(EARTH.mass, EARTH.radius);
+        let escape = Speed::of_escape(EARTH.mass, EARTH.radius);
+
+        assert!((escape - Speed::in_m_per_s(11_186.0)).abs() < Speed::in_m_per_s(5.0));
+        assert!((escape / circular - 2f64.sqrt()).abs() < 1e-12);
+        assert!(
+            (Speed::of_orbit_at(EARTH.mass, EARTH.radius, EARTH.radius) - circular).abs()
+                < Speed::in_m_per_s(1e-9)
+        );
+    }
+
+    #[test]
+    fn escape_energy_balances_potential() {
+        let mass = 1000.0 * KG;
+        let kinetic = mass * Speed::of_escape(EARTH.mass, EARTH.radius).squared() / 2.0;
+        let potential = Energy::of_gravity(EARTH.mass, mass, EARTH.radius);
+
+        assert!(((kinetic + potential) / kinetic).abs() < 1e-12);
+        assert!(
+            (Acceleration::of_surface(EARTH.mass, EARTH.radius) - Acceleration::in_m_per_s2(9.82))
+                .abs()
+                < Acceleration::in_m_per_s2(0.01)
+        );
+    }
+
+    #[test]
+    fn earth_radii() {
+        let soi = Length::sphere_of_influence(AU, EARTH.mass, SUN.mass);
+        let hill = Length::hill_sphere(AU, 0.0167, EARTH.mass, SUN.mass);
+        let roche = Length::roche_limit(EARTH.mass, MOON.mass, MOON.radius);
+
+        assert!((soi - 925_000.0 * KM).abs() < 5_000.0 * KM);
+        assert!((hill - 1_471_000.0 * KM).abs() < 5_000.0 * KM);
+        assert!((roche - 9_500.0 * KM).abs() < 100.0 * KM);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Amount, Energy, Pressure, Temperature, Volume};
+use crate::{Amount, AmountInv, Energy, Pressure, Temperature, Volume};
 
 scalar! {
     struct GasConstant(f64) {
@@ -14,6 +14,7 @@ scalar_div!(GasConstant | Volume = PressurePerAmountTemperature);
 scalar_div!(EnergyPerAmount | Temperature = GasConstant);
 scalar_div!(EnergyPerTemperature | Amount = GasConstant);
 scalar_div!(GasConstant | Pressure = VolumePerAmountTemperature);
+scalar_div!(GasConstant | AmountInv = EnergyPerTemperature);
 
 scalar! {
     struct PressurePerAmountTemperature(f64) {
@@ -90,18 +91,19 @@ mod test {
     #[test]
     fn gas_constant_unit_composition() {
         const GC: GasConstant = GasConstant::in_j_per_mol_k(1.0);
-        assert_eq!(GC, N / M2 * M3 / MOL / K);
-        assert_eq!(MOL, PA * M3 / K / GC);
+        const T: Temperature = Temperature::from_origin(K);
+        assert_eq!(GC, N / M2 * M3 / MOL / T);
+        assert_eq!(MOL, PA * M3 / T / GC);
     }
 
     #[test]
     fn ideal_gas_state_change() {
         let p1 = PA;
-        let t1 = K;
+        let t1 = Temperature::in_k(1.0);
         let v1 = M3;
 
         let p2 = 2.0 * PA;
-        let t2 = 0.5 * K;
+        let t2 = Temperature::in_k(0.5);
 
         // p1 * v1 / t1 = p2 * v2 / t2
         let v2 = p1 * v1 / t1 / p2 * t2;
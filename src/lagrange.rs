@@ -0,0 +1,228 @@
+use crate::{Distance, Length, Mass, Orbit, Position, TimeIndex, Vector2};
+
+/// https://en.wikipedia.org/wiki/Lagrange_point
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum LagrangePoint {
+    /// Between the two bodies
+    L1,
+    /// Beyond the secondary
+    L2,
+    /// Beyond the primary, opposite the secondary
+    L3,
+    /// Leading the secondary by 60°
+    L4,
+    /// Trailing the secondary by 60°
+    L5,
+}
+
+impl LagrangePoint {
+    pub const ALL: [LagrangePoint; 5] = [
+        LagrangePoint::L1,
+        LagrangePoint::L2,
+        LagrangePoint::L3,
+        LagrangePoint::L4,
+        LagrangePoint::L5,
+    ];
+}
+
+/// The Lagrange points of a secondary body in a circular orbit around a primary.
+///
+/// Offsets are in the frame rotating with the secondary, with the primary at the origin
+/// and the secondary on the positive x-axis.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LagrangePoints {
+    pub primary_mass: Mass,
+    pub secondary_mass: Mass,
+    pub separation: Length,
+}
+
+impl LagrangePoints {
+    #[inline]
+    pub fn new(primary_mass: Mass, secondary_mass: Mass, separation: Length) -> Self {
+        debug_assert!(secondary_mass > Mass::zero() && primary_mass >= secondary_mass);
+
+        Self {
+            primary_mass,
+            secondary_mass,
+            separation,
+        }
+    }
+
+    /// Fraction of the total mass in the secondary, `μ = m₂ / (m₁ + m₂)`
+    #[inline]
+    pub fn mass_ratio(&self) -> f64 {
+        self.secondary_mass / (self.primary_mass + self.secondary_mass)
+    }
+
+    #[inline]
+    pub fn offset(&self, point: LagrangePoint) -> Distance {
+        let (x, y) = Self::coordinates(point, self.mass_ratio());
+        Vector2 {
+            x: self.separation * x,
+            y: self.separation * y,
+        }
+    }
+
+    #[inline]
+    pub fn offsets(&self) -> [Distance; 5] {
+        LagrangePoint::ALL.map(|point| self.offset(point))
+    }
+
+    /// Coordinates in units of the separation
+    fn coordinates(point: LagrangePoint, mu: f64) -> (f64, f64) {
+        const SIN_60: f64 = 0.866_025_403_784_438_6;
+
+        match point {
+            LagrangePoint::L1 => (1.0 - Self::collinear(point, mu), 0.0),
+            LagrangePoint::L2 => (1.0 + Self::collinear(point, mu), 0.0),
+            LagrangePoint::L3 => (-Self::collinear(point, mu), 0.0),
+            LagrangePoint::L4 => (0.5, SIN_60),
+            LagrangePoint::L5 => (0.5, -SIN_60),
+        }
+    }
+
+    /// Solves the quintic for the distance of a collinear point from the secondary,
+    /// or from the primary for L3, by Newton's method
+    fn collinear(point: LagrangePoint, mu: f64) -> f64 {
+        let (coefficients, mut gamma) = match point {
+            LagrangePoint::L1 => (
+                [1.0, mu - 3.0, 3.0 - 2.0 * mu, -mu, 2.0 * mu, -mu],
+                (mu / 3.0).cbrt(),
+            ),
+            LagrangePoint::L2 => (
+                [1.0, 3.0 - mu, 3.0 - 2.0 * mu, -mu, -2.0 * mu, -mu],
+                (mu / 3.0).cbrt(),
+            ),
+            LagrangePoint::L3 => (
+                [
+                    1.0,
+                    2.0 + mu,
+                    1.0 + 2.0 * mu,
+                    mu - 1.0,
+                    2.0 * (mu - 1.0),
+                    mu - 1.0,
+                ],
+                1.0 - 7.0 * mu / 12.0,
+            ),
+            LagrangePoint::L4 | LagrangePoint::L5 => unreachable!(),
+        };
+
+        for _ in 0..Self::MAX_ITERATIONS {
+            let (value, slope) = coefficients.iter().fold((0.0, 0.0), |(value, slope), c| {
+                (value * gamma + c, slope * gamma + value)
+            });
+            let step = value / slope;
+            gamma -= step;
+
+            if step.abs() < Self::TOLERANCE {
+                break;
+            }
+        }
+
+        gamma
+    }
+
+    const MAX_ITERATIONS: usize = 50;
+
+    const TOLERANCE: f64 = 1e-14;
+}
+
+impl Orbit {
+    /// Position of a Lagrange point of a body with `mass` following this orbit,
+    /// with the central body at the origin.
+    ///
+    /// For eccentric orbits the points keep their shape relative to the current separation.
+    pub fn lagrange_point(&self, mass: Mass, point: LagrangePoint, time: TimeIndex) -> Position {
+        let secondary = self.position(time);
+        let normal = Vector2 {
+            x: -secondary.y,
+            y: secondary.x,
+        };
+
+        let points = LagrangePoints::new(self.central_mass, mass, secondary.magnitude());
+        let (x, y) = LagrangePoints::coordinates(point, points.mass_ratio());
+
+        Position::from_origin(secondary * x + normal * y)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::catalogue::{EARTH, MOON, SUN};
+    use crate::constants::G;
+    use crate::{Angle, KM};
+
+    /// Net acceleration in the rotating frame, which vanishes at every Lagrange point
+    fn residual(points: &LagrangePoints, offset: Distance) -> f64 {
+        let d = points.separation.value;
+        let (x, y) = (offset.x.value, offset.y.value);
+        let gm1 = G.value.value * points.primary_mass.value;
+        let gm2 = G.value.value * points.secondary_mass.value;
+        let omega_squared = (gm1 + gm2) / d.powi(3);
+        let barycenter = d * points.mass_ratio();
+
+        let r1 = (x * x + y * y).sqrt();
+        let r2 = ((x - d).powi(2) + y * y).sqrt();
+        let ax =
+            -gm1 * x / r1.powi(3) - gm2 * (x - d) / r2.powi(3) + omega_squared * (x - barycenter);
+        let ay = -gm1 * y / r1.powi(3) - gm2 * y / r2.powi(3) + omega_squared * y;
+
+        (ax * ax + ay * ay).sqrt() / (omega_squared * d)
+    }
+
+    #[test]
+    fn earth_moon_points() {
+        let points = LagrangePoints::new(EARTH.mass, MOON.mass, 384_400.0 * KM);
+        let [l1, l2, l3, l4, l5] = points.offsets();
+
+        assert!((l1.x - 326_400.0 * KM).abs() < 500.0 * KM);
+        assert!((l2.x - 448_900.0 * KM).abs() < 500.0 * KM);
+        assert!((l3.x + 381_700.0 * KM).abs() < 500.0 * KM);
+        assert!((l4.magnitude() - points.separation).abs() < KM);
+        assert_eq!(l4.x, l5.x);
+        assert_eq!(l4.y, -l5.y);
+    }
+
+    #[test]
+    fn points_balance_in_rotating_frame() {
+        for (primary, secondary, separation) in [
+            (SUN.mass, EARTH.mass, crate::AU),
+            (EARTH.mass, MOON.mass, 384_400.0 * KM),
+            (EARTH.mass, EARTH.mass, 1000.0 * KM),
+        ] {
+            let points = LagrangePoints::new(primary, secondary, separation);
+
+            for offset in points.offsets() {
+                assert!(residual(&points, offset) < 1e-9, "{:?}", offset);
+            }
+        }
+    }
+
+    #[test]
+    fn points_follow_orbit() {
+        let orbit = Orbit::circular(384_400.0 * KM, TimeIndex::default(), EARTH.mass);
+        let time = orbit.epoch + orbit.period() / 3.0;
+        let moon = orbit.position(time);
+
+        let l1 = orbit
+            .lagrange_point(MOON.mass, LagrangePoint::L1, time)
+            .value;
+        let l4 = orbit
+            .lagrange_point(MOON.mass, LagrangePoint::L4, time)
+            .value;
+        let angle = |v: Distance| Angle::atan2(v.y.value, v.x.value);
+
+        assert!((angle(l1) - angle(moon)).abs() < Angle::in_rad(1e-12));
+        assert!(l1.magnitude() < moon.magnitude());
+        assert!((l4.magnitude() - moon.magnitude()).abs() < KM);
+        assert!(
+            ((angle(l4) - angle(moon))
+                .value
+                .rem_euclid(std::f64::consts::TAU)
+                - std::f64::consts::FRAC_PI_3)
+                .abs()
+                < 1e-9
+        );
+    }
+}
@@ -1,9 +1,10 @@
-use crate::{Area, Duration, Mass, New, Squared};
+use crate::{Area, Duration, Mass, Squared};
 
 pub const M: Length = Length::in_m(1.0);
 pub const KM: Length = Length::in_m(1e3);
 pub const AU: Length = Length::in_m(1.495978707e11);
 pub const LY: Length = Length::in_m(9.460_730_472_580_8e15);
+pub const PC: Length = Length::in_m(3.085_677_581_491_367e16);
 
 pub type Radius = Length;
 pub type Distance = crate::Vector2<Length>;
@@ -27,14 +28,10 @@ impl Length {
     #[inline]
     pub fn of_orbit(mass: Mass, period: Duration) -> Self {
         use crate::constants::G;
-        use std::f64::consts::TAU;
 
-        const G_OVER_TAU_SQUARED: f64 = G / TAU.squared();
-        const ONE_THIRD: f64 = 1.0 / 3.0;
-
-        let meters_cubed = G_OVER_TAU_SQUARED * mass.value * period.value * period.value;
-        let meters = meters_cubed.powf(ONE_THIRD);
-        Length::in_m(meters)
+        let angular_frequency = std::f64::consts::TAU / period;
+        let volume = G.value * mass / angular_frequency.squared();
+        Length::in_m(volume.value.cbrt())
     }
 }
 
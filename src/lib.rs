@@ -16,9 +16,8 @@ use std::fmt::{Display, Formatter};
 #[macro_use]
 mod macros;
 
-pub mod constants {
-    pub const G: f64 = 6.6743015e-11;
-}
+pub mod catalogue;
+pub mod constants;
 
 macro_rules! modules {
     (
@@ -36,22 +35,43 @@ modules! {
     amount,
     angle,
     area,
+    calendar,
+    charge,
+    climate,
+    clock,
     credits,
+    duration_format,
+    elements,
     energy,
+    ephemeris,
+    fixed,
     force,
+    gravity,
     ideal_gas,
+    lagrange,
     length,
+    maneuver,
     mass,
     mass_rate,
+    nbody,
+    orbit,
     pixel,
+    point,
     polar,
     population,
     position,
     power,
     pressure,
+    rocket,
+    scene,
+    scheduler,
+    sector,
+    spectrum,
     speed,
     spherical,
+    star,
     temperature,
+    tick,
     time,
     unit,
     vector,
@@ -82,6 +102,33 @@ impl Sqrt for f32 {
     }
 }
 
+/// Square root of the sum of squares, as used for the magnitude of a vector
+pub trait Norm: Sized {
+    fn norm<const N: usize>(components: [Self; N]) -> Self;
+}
+
+impl Norm for f64 {
+    #[inline]
+    fn norm<const N: usize>(components: [Self; N]) -> Self {
+        components
+            .iter()
+            .rev()
+            .fold(0.0, |sum, c| c * c + sum)
+            .sqrt()
+    }
+}
+
+impl Norm for f32 {
+    #[inline]
+    fn norm<const N: usize>(components: [Self; N]) -> Self {
+        components
+            .iter()
+            .rev()
+            .fold(0.0, |sum, c| c * c + sum)
+            .sqrt()
+    }
+}
+
 pub trait Squared {
     type Output;
     fn squared(self) -> Self::Output;
@@ -103,6 +150,18 @@ impl const Squared for f32 {
     }
 }
 
+pub trait Zero {
+    const ZERO: Self;
+}
+
+impl Zero for f64 {
+    const ZERO: Self = 0.0;
+}
+
+impl Zero for f32 {
+    const ZERO: Self = 0.0;
+}
+
 pub trait Wrapper: Copy {
     type Inner;
     fn value(self) -> Self::Inner;
@@ -156,3 +215,8 @@ pub trait Dot<Rhs = Self> {
     type Output;
     fn dot(self, rhs: Rhs) -> Self::Output;
 }
+
+pub trait Cross<Rhs = Self> {
+    type Output;
+    fn cross(self, rhs: Rhs) -> Self::Output;
+}
@@ -5,6 +5,8 @@ macro_rules! scalar {
     } => {
         #[repr(transparent)]
         #[derive(Debug, Default, Copy, Clone)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
         pub struct $scalar {
             pub value: $base,
         }
@@ -19,7 +21,7 @@ macro_rules! scalar {
 
             #[inline]
             pub const fn zero() -> Self {
-                Self::new(0.0)
+                Self::new(<$base as $crate::Zero>::ZERO)
             }
 
             #[inline]
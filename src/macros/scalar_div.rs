@@ -7,7 +7,9 @@ macro_rules! scalar_div {
             type Output = $res;
             #[inline]
             fn div(self, rhs: $den) -> Self::Output {
-                Self::Output::new(self.value() / rhs.value())
+                <Self::Output as $crate::New>::new(
+                    <$num as $crate::New>::value(self) / <$den as $crate::New>::value(rhs),
+                )
             }
         }
 
@@ -15,7 +17,9 @@ macro_rules! scalar_div {
             type Output = $res;
             #[inline]
             fn div(self, rhs: $den) -> Self::Output {
-                Self::Output::new(self.value() / rhs.value())
+                <Self::Output as $crate::New>::new(
+                    <$num as $crate::New>::value(*self) / <$den as $crate::New>::value(rhs),
+                )
             }
         }
 
@@ -23,7 +27,9 @@ macro_rules! scalar_div {
             type Output = $res;
             #[inline]
             fn div(self, rhs: &$den) -> Self::Output {
-                Self::Output::new(self.value() / rhs.value())
+                <Self::Output as $crate::New>::new(
+                    <$num as $crate::New>::value(self) / <$den as $crate::New>::value(*rhs),
+                )
             }
         }
 
@@ -31,7 +37,9 @@ macro_rules! scalar_div {
             type Output = $res;
             #[inline]
             fn div(self, rhs: &$den) -> Self::Output {
-                Self::Output::new(self.value() / rhs.value())
+                <Self::Output as $crate::New>::new(
+                    <$num as $crate::New>::value(*self) / <$den as $crate::New>::value(*rhs),
+                )
             }
         }
 
@@ -39,7 +47,9 @@ macro_rules! scalar_div {
             type Output = $den;
             #[inline]
             fn div(self, rhs: $res) -> Self::Output {
-                Self::Output::new(self.value() / rhs.value())
+                <Self::Output as $crate::New>::new(
+                    <$num as $crate::New>::value(self) / <$res as $crate::New>::value(rhs),
+                )
             }
         }
 
@@ -47,7 +57,9 @@ macro_rules! scalar_div {
             type Output = $den;
             #[inline]
             fn div(self, rhs: $res) -> Self::Output {
-                Self::Output::new(self.value() / rhs.value())
+                <Self::Output as $crate::New>::new(
+                    <$num as $crate::New>::value(*self) / <$res as $crate::New>::value(rhs),
+                )
             }
         }
 
@@ -55,7 +67,9 @@ macro_rules! scalar_div {
             type Output = $den;
             #[inline]
             fn div(self, rhs: &$res) -> Self::Output {
-                Self::Output::new(self.value() / rhs.value())
+                <Self::Output as $crate::New>::new(
+                    <$num as $crate::New>::value(self) / <$res as $crate::New>::value(*rhs),
+                )
             }
         }
 
@@ -63,7 +77,9 @@ macro_rules! scalar_div {
             type Output = $den;
             #[inline]
             fn div(self, rhs: &$res) -> Self::Output {
-                Self::Output::new(self.value() / rhs.value())
+                <Self::Output as $crate::New>::new(
+                    <$num as $crate::New>::value(*self) / <$res as $crate::New>::value(*rhs),
+                )
             }
         }
 
@@ -71,7 +87,9 @@ macro_rules! scalar_div {
             type Output = $num;
             #[inline]
             fn mul(self, rhs: $den) -> Self::Output {
-                Self::Output::new(self.value() * rhs.value())
+                <Self::Output as $crate::New>::new(
+                    <$res as $crate::New>::value(self) * <$den as $crate::New>::value(rhs),
+                )
             }
         }
 
@@ -79,7 +97,9 @@ macro_rules! scalar_div {
             type Output = $num;
             #[inline]
             fn mul(self, rhs: $den) -> Self::Output {
-                Self::Output::new(self.value() * rhs.value())
+                <Self::Output as $crate::New>::new(
+                    <$res as $crate::New>::value(*self) * <$den as $crate::New>::value(rhs),
+                )
             }
         }
 
@@ -87,7 +107,9 @@ macro_rules! scalar_div {
             type Output = $num;
             #[inline]
             fn mul(self, rhs: &$den) -> Self::Output {
-                Self::Output::new(self.value() * rhs.value())
+                <Self::Output as $crate::New>::new(
+                    <$res as $crate::New>::value(self) * <$den as $crate::New>::value(*rhs),
+                )
             }
         }
 
@@ -95,7 +117,9 @@ macro_rules! scalar_div {
             type Output = $num;
             #[inline]
             fn mul(self, rhs: &$den) -> Self::Output {
-                Self::Output::new(self.value() * rhs.value())
+                <Self::Output as $crate::New>::new(
+                    <$res as $crate::New>::value(*self) * <$den as $crate::New>::value(*rhs),
+                )
             }
         }
 
@@ -103,7 +127,9 @@ macro_rules! scalar_div {
             type Output = $num;
             #[inline]
             fn mul(self, rhs: $res) -> Self::Output {
-                Self::Output::new(self.value() * rhs.value())
+                <Self::Output as $crate::New>::new(
+                    <$den as $crate::New>::value(self) * <$res as $crate::New>::value(rhs),
+                )
             }
         }
 
@@ -111,7 +137,9 @@ macro_rules! scalar_div {
             type Output = $num;
             #[inline]
             fn mul(self, rhs: $res) -> Self::Output {
-                Self::Output::new(self.value() * rhs.value())
+                <Self::Output as $crate::New>::new(
+                    <$den as $crate::New>::value(*self) * <$res as $crate::New>::value(rhs),
+                )
             }
         }
 
@@ -119,7 +147,9 @@ macro_rules! scalar_div {
             type Output = $num;
             #[inline]
             fn mul(self, rhs: &$res) -> Self::Output {
-                Self::Output::new(self.value() * rhs.value())
+                <Self::Output as $crate::New>::new(
+                    <$den as $crate::New>::value(self) * <$res as $crate::New>::value(*rhs),
+                )
             }
         }
 
@@ -127,16 +157,18 @@ macro_rules! scalar_div {
             type Output = $num;
             #[inline]
             fn mul(self, rhs: &$res) -> Self::Output {
-                Self::Output::new(self.value() * rhs.value())
+                <Self::Output as $crate::New>::new(
+                    <$den as $crate::New>::value(*self) * <$res as $crate::New>::value(*rhs),
+                )
             }
         }
 
         paste::item! {
             #[test]
             fn [<$num:snake _ $den:snake _ $res:snake _conversion_tests>] () {
-                let numerator = $num::new(6.0);
-                let denominator = $den::new(2.0);
-                let result = $res::new(3.0);
+                let numerator = <$num as $crate::New>::new(6.0);
+                let denominator = <$den as $crate::New>::new(2.0);
+                let result = <$res as $crate::New>::new(3.0);
 
                 assert_eq!(result, numerator / denominator);
                 assert_eq!(numerator, result * denominator);
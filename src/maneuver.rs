@@ -0,0 +1,291 @@
+use crate::{Angle, AngularSpeed, Duration, Length, Mass, Speed, Sqrt, TimeIndex};
+
+impl Speed {
+    /// Delta-v to turn a velocity of `speed` through `angle` without changing its magnitude
+    #[inline]
+    pub fn of_plane_change(speed: Speed, angle: Angle) -> Self {
+        speed * (2.0 * (angle / 2.0).sin()).abs()
+    }
+}
+
+/// Delta-v of a single burn from one velocity to another at an angle to it
+#[inline]
+fn burn(from: Speed, to: Speed, angle: Angle) -> Speed {
+    (from * from + to * to - from * to * (2.0 * angle.cos())).sqrt()
+}
+
+/// A two-burn transfer between coplanar or inclined circular orbits around a central mass
+/// https://en.wikipedia.org/wiki/Hohmann_transfer_orbit
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Hohmann {
+    pub central_mass: Mass,
+    pub from: Length,
+    pub to: Length,
+    /// Inclination change, made during the burn at the larger radius where it is cheapest
+    pub inclination: Angle,
+}
+
+impl Hohmann {
+    #[inline]
+    pub fn new(central_mass: Mass, from: Length, to: Length) -> Self {
+        debug_assert!(from > Length::zero() && to > Length::zero());
+
+        Self {
+            central_mass,
+            from,
+            to,
+            inclination: Angle::zero(),
+        }
+    }
+
+    #[inline]
+    pub fn with_inclination_change(mut self, inclination: Angle) -> Self {
+        self.inclination = inclination;
+        self
+    }
+
+    #[inline]
+    pub fn semi_major_axis(&self) -> Length {
+        (self.from + self.to) / 2.0
+    }
+
+    pub fn departure_burn(&self) -> Speed {
+        let circular = Speed::of_circular_orbit(self.central_mass, self.from);
+        let transfer = Speed::of_orbit_at(self.central_mass, self.from, self.semi_major_axis());
+        let angle = if self.from > self.to {
+            self.inclination
+        } else {
+            Angle::zero()
+        };
+
+        burn(circular, transfer, angle)
+    }
+
+    pub fn arrival_burn(&self) -> Speed {
+        let transfer = Speed::of_orbit_at(self.central_mass, self.to, self.semi_major_axis());
+        let circular = Speed::of_circular_orbit(self.central_mass, self.to);
+        let angle = if self.from > self.to {
+            Angle::zero()
+        } else {
+            self.inclination
+        };
+
+        burn(transfer, circular, angle)
+    }
+
+    #[inline]
+    pub fn delta_v(&self) -> Speed {
+        self.departure_burn() + self.arrival_burn()
+    }
+
+    /// Half the period of the transfer orbit
+    #[inline]
+    pub fn transfer_time(&self) -> Duration {
+        Duration::of_orbit(self.semi_major_axis(), self.central_mass) / 2.0
+    }
+
+    /// Angle the target must lead the departing body by when the first burn is made
+    #[inline]
+    pub fn phase_angle(&self) -> Angle {
+        let target = AngularSpeed::of_orbit(self.central_mass, self.to);
+        Angle::PI - target * self.transfer_time()
+    }
+
+    /// Time between successive launch windows, or `None` if both orbits have the same period
+    /// and the phase never changes
+    #[inline]
+    pub fn synodic_period(&self) -> Option<Duration> {
+        let relative_motion = self.relative_motion();
+        if relative_motion == AngularSpeed::zero() {
+            return None;
+        }
+
+        Some(Angle::TAU / relative_motion.abs())
+    }
+
+    /// The first launch window at or after `now`, given the angle the target leads by at `now`,
+    /// or `None` if both orbits have the same period
+    pub fn next_window(&self, now: TimeIndex, phase: Angle) -> Option<TimeIndex> {
+        let synodic_period = self.synodic_period()?;
+        let wait = (self.phase_angle() - phase) / self.relative_motion();
+        let wait = wait.value.rem_euclid(synodic_period.value);
+        Some(now + Duration::in_s(wait))
+    }
+
+    /// Rate at which the target's lead over the departing body changes
+    #[inline]
+    fn relative_motion(&self) -> AngularSpeed {
+        AngularSpeed::of_orbit(self.central_mass, self.to)
+            - AngularSpeed::of_orbit(self.central_mass, self.from)
+    }
+}
+
+/// A three-burn transfer between coplanar circular orbits via an intermediate apoapsis,
+/// cheaper than a Hohmann transfer when the ratio of radii is above about 11.94
+/// https://en.wikipedia.org/wiki/Bi-elliptic_transfer
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BiElliptic {
+    pub central_mass: Mass,
+    pub from: Length,
+    pub to: Length,
+    /// Apoapsis of both transfer orbits, beyond both `from` and `to`
+    pub apoapsis: Length,
+}
+
+impl BiElliptic {
+    #[inline]
+    pub fn new(central_mass: Mass, from: Length, to: Length, apoapsis: Length) -> Self {
+        debug_assert!(apoapsis >= from && apoapsis >= to);
+
+        Self {
+            central_mass,
+            from,
+            to,
+            apoapsis,
+        }
+    }
+
+    /// The magnitude of each burn in the order they are made
+    pub fn burns(&self) -> [Speed; 3] {
+        let mass = self.central_mass;
+        let first = (self.from + self.apoapsis) / 2.0;
+        let second = (self.to + self.apoapsis) / 2.0;
+
+        [
+            (Speed::of_orbit_at(mass, self.from, first)
+                - Speed::of_circular_orbit(mass, self.from))
+            .abs(),
+            (Speed::of_orbit_at(mass, self.apoapsis, second)
+                - Speed::of_orbit_at(mass, self.apoapsis, first))
+            .abs(),
+            (Speed::of_orbit_at(mass, self.to, second) - Speed::of_circular_orbit(mass, self.to))
+                .abs(),
+        ]
+    }
+
+    #[inline]
+    pub fn delta_v(&self) -> Speed {
+        self.burns().iter().copied().sum()
+    }
+
+    /// Half the period of each of the two transfer orbits
+    #[inline]
+    pub fn transfer_time(&self) -> Duration {
+        let first = Duration::of_orbit((self.from + self.apoapsis) / 2.0, self.central_mass);
+        let second = Duration::of_orbit((self.to + self.apoapsis) / 2.0, self.central_mass);
+        (first + second) / 2.0
+    }
+}
+
+/// Delta-v to incline a circular orbit by `inclination` with a single burn at a node
+#[inline]
+pub fn plane_change(central_mass: Mass, radius: Length, inclination: Angle) -> Speed {
+    Speed::of_plane_change(Speed::of_circular_orbit(central_mass, radius), inclination)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::catalogue::{EARTH, SUN};
+    use crate::{AU, DEG, KM};
+
+    fn assert_close(expected: Speed, actual: Speed) {
+        assert!(
+            (expected - actual).abs() < Speed::in_m_per_s(1.0),
+            "{:?} != {:?}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn leo_to_geo() {
+        let leo = EARTH.radius + 300.0 * KM;
+        let geo = Length::in_m(42_164e3);
+        let hohmann = Hohmann::new(EARTH.mass, leo, geo);
+
+        assert_close(Speed::in_m_per_s(2_427.8), hohmann.departure_burn());
+        assert_close(Speed::in_m_per_s(1_467.0), hohmann.arrival_burn());
+        assert!((hohmann.transfer_time() - Duration::in_hr(5.27)).abs() < Duration::in_hr(0.01));
+
+        let inward = Hohmann::new(EARTH.mass, geo, leo);
+        assert_close(hohmann.delta_v(), inward.delta_v());
+    }
+
+    #[test]
+    fn inclination_change_is_cheapest_combined_at_apoapsis() {
+        let leo = EARTH.radius + 300.0 * KM;
+        let geo = Length::in_m(42_164e3);
+        let inclination = 28.5 * DEG;
+
+        let combined = Hohmann::new(EARTH.mass, leo, geo).with_inclination_change(inclination);
+        let separate = Hohmann::new(EARTH.mass, leo, geo).delta_v()
+            + plane_change(EARTH.mass, geo, inclination);
+
+        assert_close(Speed::in_m_per_s(1_831.0), combined.arrival_burn());
+        assert!(combined.delta_v() < separate);
+        assert_close(
+            Speed::of_circular_orbit(EARTH.mass, geo),
+            plane_change(EARTH.mass, geo, 60.0 * DEG),
+        );
+    }
+
+    #[test]
+    fn earth_to_mars_window() {
+        let hohmann = Hohmann::new(SUN.mass, AU, 1.524 * AU);
+        let now = TimeIndex::default();
+
+        assert!((hohmann.phase_angle() - 44.3 * DEG).abs() < 0.1 * DEG);
+        let synodic_period = hohmann.synodic_period().unwrap();
+        assert!((synodic_period - Duration::in_d(780.0)).abs() < Duration::in_d(1.0));
+
+        assert_eq!(Some(now), hohmann.next_window(now, hohmann.phase_angle()));
+
+        let window = hohmann
+            .next_window(now, hohmann.phase_angle() + 10.0 * DEG)
+            .unwrap();
+        let lead = hohmann.phase_angle() + 10.0 * DEG + hohmann.relative_motion() * (window - now);
+        assert!((lead - hohmann.phase_angle()).abs() < 1e-9 * DEG);
+        assert!(window - now < synodic_period);
+    }
+
+    #[test]
+    fn same_orbit_has_no_window() {
+        let hohmann = Hohmann::new(SUN.mass, AU, AU);
+
+        assert_eq!(None, hohmann.synodic_period());
+        assert_eq!(
+            None,
+            hohmann.next_window(TimeIndex::default(), Angle::zero())
+        );
+    }
+
+    #[test]
+    fn bi_elliptic_beats_hohmann_for_large_ratios() {
+        let from = 7000.0 * KM;
+        let to = from * 15.0;
+
+        let hohmann = Hohmann::new(EARTH.mass, from, to);
+        let bi_elliptic = BiElliptic::new(EARTH.mass, from, to, to * 4.0);
+        let direct = BiElliptic::new(EARTH.mass, from, to, to);
+
+        assert!(bi_elliptic.delta_v() < hohmann.delta_v());
+        assert!(bi_elliptic.transfer_time() > hohmann.transfer_time());
+        assert!((direct.delta_v() - hohmann.delta_v()).abs() < Speed::in_m_per_s(1e-6));
+        assert!(direct.burns()[2].abs() < Speed::in_m_per_s(1e-6));
+    }
+
+    #[test]
+    fn bi_elliptic_inward_is_symmetric() {
+        let from = 7000.0 * KM;
+        let to = from * 15.0;
+
+        let outward = BiElliptic::new(EARTH.mass, from, to, to * 4.0);
+        let inward = BiElliptic::new(EARTH.mass, to, from, to * 4.0);
+        let [a, b, c] = outward.burns();
+
+        assert_eq!([c, b, a], inward.burns());
+        assert_close(outward.delta_v(), inward.delta_v());
+        assert!(inward.burns().iter().all(|burn| *burn >= Speed::zero()));
+    }
+}
@@ -0,0 +1,682 @@
+use crate::constants::G;
+use crate::{
+    Acceleration, Duration, Energy, Length, Mass, Momentum, Position, Position3, Speed, Vector2,
+    Vector3, M,
+};
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Div, Mul, Sub};
+
+pub type PointMass2 = PointMass<Vector2<f64>>;
+pub type PointMass3 = PointMass<Vector3<f64>>;
+
+pub type NBody2 = NBody<Vector2<f64>>;
+pub type NBody3 = NBody<Vector3<f64>>;
+
+/// A point mass in an `NBody` simulation
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PointMass<S: Space> {
+    pub mass: Mass,
+    pub position: S::Position,
+    pub velocity: S::Velocity,
+}
+
+/// The plain SI vectors that the simulator works in, and their typed equivalents
+pub trait Space:
+    Copy
+    + Default
+    + Debug
+    + PartialEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<f64, Output = Self>
+    + Div<f64, Output = Self>
+    + AddAssign
+{
+    type Position: Copy + Debug + PartialEq;
+    type Velocity: Copy + Debug + PartialEq;
+    type Acceleration;
+    type Momentum;
+
+    /// Number of children of a Barnes–Hut cell
+    const CELLS: usize;
+
+    fn norm_squared(self) -> f64;
+
+    /// Largest absolute component
+    fn max_norm(self) -> f64;
+
+    /// Index of the child cell of a cell centred on `center` that contains `self`
+    fn cell(self, center: Self) -> usize;
+
+    fn cell_center(center: Self, half_size: f64, cell: usize) -> Self;
+
+    fn from_position(position: Self::Position) -> Self;
+
+    fn position(self) -> Self::Position;
+
+    fn from_velocity(velocity: Self::Velocity) -> Self;
+
+    fn velocity(self) -> Self::Velocity;
+
+    fn acceleration(self) -> Self::Acceleration;
+
+    fn momentum(self) -> Self::Momentum;
+}
+
+impl Space for Vector2<f64> {
+    type Position = Position;
+    type Velocity = Vector2<Speed>;
+    type Acceleration = Vector2<Acceleration>;
+    type Momentum = Vector2<Momentum>;
+
+    const CELLS: usize = 4;
+
+    #[inline]
+    fn norm_squared(self) -> f64 {
+        self.magnitude_squared()
+    }
+
+    #[inline]
+    fn max_norm(self) -> f64 {
+        self.x.abs().max(self.y.abs())
+    }
+
+    #[inline]
+    fn cell(self, center: Self) -> usize {
+        (self.x >= center.x) as usize | ((self.y >= center.y) as usize) << 1
+    }
+
+    #[inline]
+    fn cell_center(center: Self, half_size: f64, cell: usize) -> Self {
+        let offset = |bit: usize| if cell & bit == 0 { -0.5 } else { 0.5 } * half_size;
+        center
+            + Vector2 {
+                x: offset(1),
+                y: offset(2),
+            }
+    }
+
+    #[inline]
+    fn from_position(position: Position) -> Self {
+        position.value / M
+    }
+
+    #[inline]
+    fn position(self) -> Position {
+        Position::from_origin(self * M)
+    }
+
+    #[inline]
+    fn from_velocity(velocity: Vector2<Speed>) -> Self {
+        velocity / METERS_PER_SECOND
+    }
+
+    #[inline]
+    fn velocity(self) -> Vector2<Speed> {
+        self * METERS_PER_SECOND
+    }
+
+    #[inline]
+    fn acceleration(self) -> Vector2<Acceleration> {
+        self * Acceleration::in_m_per_s2(1.0)
+    }
+
+    #[inline]
+    fn momentum(self) -> Vector2<Momentum> {
+        self * Momentum::in_kg_m_per_s(1.0)
+    }
+}
+
+impl Space for Vector3<f64> {
+    type Position = Position3;
+    type Velocity = Vector3<Speed>;
+    type Acceleration = Vector3<Acceleration>;
+    type Momentum = Vector3<Momentum>;
+
+    const CELLS: usize = 8;
+
+    #[inline]
+    fn norm_squared(self) -> f64 {
+        self.magnitude_squared()
+    }
+
+    #[inline]
+    fn max_norm(self) -> f64 {
+        self.x.abs().max(self.y.abs()).max(self.z.abs())
+    }
+
+    #[inline]
+    fn cell(self, center: Self) -> usize {
+        (self.x >= center.x) as usize
+            | ((self.y >= center.y) as usize) << 1
+            | ((self.z >= center.z) as usize) << 2
+    }
+
+    #[inline]
+    fn cell_center(center: Self, half_size: f64, cell: usize) -> Self {
+        let offset = |bit: usize| if cell & bit == 0 { -0.5 } else { 0.5 } * half_size;
+        center
+            + Vector3 {
+                x: offset(1),
+                y: offset(2),
+                z: offset(4),
+            }
+    }
+
+    #[inline]
+    fn from_position(position: Position3) -> Self {
+        position.value / M
+    }
+
+    #[inline]
+    fn position(self) -> Position3 {
+        Position3::from_origin(self * M)
+    }
+
+    #[inline]
+    fn from_velocity(velocity: Vector3<Speed>) -> Self {
+        velocity / METERS_PER_SECOND
+    }
+
+    #[inline]
+    fn velocity(self) -> Vector3<Speed> {
+        self * METERS_PER_SECOND
+    }
+
+    #[inline]
+    fn acceleration(self) -> Vector3<Acceleration> {
+        self * Acceleration::in_m_per_s2(1.0)
+    }
+
+    #[inline]
+    fn momentum(self) -> Vector3<Momentum> {
+        self * Momentum::in_kg_m_per_s(1.0)
+    }
+}
+
+const METERS_PER_SECOND: Speed = Speed::in_m_per_s(1.0);
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Integrator {
+    /// Drift-kick-drift, one force evaluation per step
+    Leapfrog,
+    /// Kick-drift-kick, two force evaluations per step
+    VelocityVerlet,
+    /// Classical fourth-order Runge–Kutta. Not symplectic, so energy drifts over long runs.
+    RungeKutta4,
+}
+
+/// A gravitational N-body simulation.
+///
+/// State is kept in plain SI vectors for speed and converted to typed values at the edges.
+#[derive(Debug, Clone)]
+pub struct NBody<S: Space> {
+    masses: Vec<f64>,
+    positions: Vec<S>,
+    velocities: Vec<S>,
+    integrator: Integrator,
+    softening: f64,
+    opening_angle: Option<f64>,
+    initial_energy: f64,
+    initial_momentum: S,
+}
+
+impl<S: Space> NBody<S> {
+    pub fn new(bodies: impl IntoIterator<Item = PointMass<S>>) -> Self {
+        let mut masses = vec![];
+        let mut positions = vec![];
+        let mut velocities = vec![];
+
+        for body in bodies {
+            masses.push(body.mass.value);
+            positions.push(S::from_position(body.position));
+            velocities.push(S::from_velocity(body.velocity));
+        }
+
+        let mut simulation = Self {
+            masses,
+            positions,
+            velocities,
+            integrator: Integrator::VelocityVerlet,
+            softening: 0.0,
+            opening_angle: None,
+            initial_energy: 0.0,
+            initial_momentum: S::default(),
+        };
+        simulation.reset_drift();
+        simulation
+    }
+
+    #[inline]
+    pub fn with_integrator(mut self, integrator: Integrator) -> Self {
+        self.integrator = integrator;
+        self
+    }
+
+    /// Plummer softening length, which limits the force between close bodies
+    #[inline]
+    pub fn with_softening(mut self, softening: Length) -> Self {
+        self.softening = softening.value;
+        self.reset_drift();
+        self
+    }
+
+    /// Uses the Barnes–Hut approximation, treating a cell as a point mass when its size divided by
+    /// its distance is below `opening_angle`. Around 0.5 is typical; zero is exact.
+    #[inline]
+    pub fn with_barnes_hut(mut self, opening_angle: f64) -> Self {
+        debug_assert!(opening_angle >= 0.0);
+        self.opening_angle = Some(opening_angle);
+        self
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.masses.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.masses.is_empty()
+    }
+
+    #[inline]
+    pub fn body(&self, index: usize) -> PointMass<S> {
+        PointMass {
+            mass: Mass::in_kg(self.masses[index]),
+            position: self.positions[index].position(),
+            velocity: self.velocities[index].velocity(),
+        }
+    }
+
+    pub fn bodies(&self) -> impl Iterator<Item = PointMass<S>> + '_ {
+        (0..self.len()).map(move |i| self.body(i))
+    }
+
+    pub fn accelerations(&self) -> Vec<S::Acceleration> {
+        let accelerations = self.accelerations_at(&self.positions);
+        accelerations.into_iter().map(S::acceleration).collect()
+    }
+
+    pub fn step(&mut self, duration: Duration) {
+        let dt = duration.value;
+
+        match self.integrator {
+            Integrator::Leapfrog => {
+                self.positions = offset(&self.positions, &self.velocities, dt / 2.0);
+                let accelerations = self.accelerations_at(&self.positions);
+                self.velocities = offset(&self.velocities, &accelerations, dt);
+                self.positions = offset(&self.positions, &self.velocities, dt / 2.0);
+            }
+            Integrator::VelocityVerlet => {
+                let accelerations = self.accelerations_at(&self.positions);
+                self.velocities = offset(&self.velocities, &accelerations, dt / 2.0);
+                self.positions = offset(&self.positions, &self.velocities, dt);
+                let accelerations = self.accelerations_at(&self.positions);
+                self.velocities = offset(&self.velocities, &accelerations, dt / 2.0);
+            }
+            Integrator::RungeKutta4 => {
+                let (x0, v0) = (&self.positions, &self.velocities);
+
+                let a1 = self.accelerations_at(x0);
+                let x1 = offset(x0, v0, dt / 2.0);
+                let v1 = offset(v0, &a1, dt / 2.0);
+
+                let a2 = self.accelerations_at(&x1);
+                let x2 = offset(x0, &v1, dt / 2.0);
+                let v2 = offset(v0, &a2, dt / 2.0);
+
+                let a3 = self.accelerations_at(&x2);
+                let x3 = offset(x0, &v2, dt);
+                let v3 = offset(v0, &a3, dt);
+
+                let a4 = self.accelerations_at(&x3);
+
+                let weighted = |k1: &[S], k2: &[S], k3: &[S], k4: &[S]| {
+                    (0..k1.len())
+                        .map(|i| (k1[i] + (k2[i] + k3[i]) * 2.0 + k4[i]) / 6.0)
+                        .collect::<Vec<_>>()
+                };
+
+                let positions = offset(x0, &weighted(v0, &v1, &v2, &v3), dt);
+                let velocities = offset(v0, &weighted(&a1, &a2, &a3, &a4), dt);
+                self.positions = positions;
+                self.velocities = velocities;
+            }
+        }
+    }
+
+    /// Kinetic plus (softened) gravitational potential energy
+    pub fn energy(&self) -> Energy {
+        Energy::in_joules(self.energy_value())
+    }
+
+    #[inline]
+    pub fn momentum(&self) -> S::Momentum {
+        self.momentum_value().momentum()
+    }
+
+    /// Change in total energy since the start, which should be zero
+    #[inline]
+    pub fn energy_drift(&self) -> Energy {
+        Energy::in_joules(self.energy_value() - self.initial_energy)
+    }
+
+    /// Change in total momentum since the start, which should be zero
+    #[inline]
+    pub fn momentum_drift(&self) -> S::Momentum {
+        (self.momentum_value() - self.initial_momentum).momentum()
+    }
+
+    fn reset_drift(&mut self) {
+        self.initial_energy = self.energy_value();
+        self.initial_momentum = self.momentum_value();
+    }
+
+    fn energy_value(&self) -> f64 {
+        let g = G.value.value;
+        let softening = self.softening * self.softening;
+
+        let mut energy = 0.0;
+        for i in 0..self.len() {
+            energy += 0.5 * self.masses[i] * self.velocities[i].norm_squared();
+
+            for j in 0..i {
+                let distance = (self.positions[j] - self.positions[i]).norm_squared() + softening;
+                energy -= g * self.masses[i] * self.masses[j] / distance.sqrt();
+            }
+        }
+        energy
+    }
+
+    fn momentum_value(&self) -> S {
+        self.velocities
+            .iter()
+            .zip(&self.masses)
+            .fold(S::default(), |sum, (&velocity, &mass)| {
+                sum + velocity * mass
+            })
+    }
+
+    fn accelerations_at(&self, positions: &[S]) -> Vec<S> {
+        match self.opening_angle {
+            Some(opening_angle) => {
+                let tree = Tree::new(positions, &self.masses);
+                (0..positions.len())
+                    .map(|i| tree.acceleration(i, opening_angle, self.softening))
+                    .collect()
+            }
+            None => self.pairwise_accelerations(positions),
+        }
+    }
+
+    fn pairwise_accelerations(&self, positions: &[S]) -> Vec<S> {
+        let mut accelerations = vec![S::default(); positions.len()];
+
+        for i in 0..positions.len() {
+            for j in 0..i {
+                let offset = positions[j] - positions[i];
+                let field = field(offset, self.softening);
+
+                accelerations[i] += field * self.masses[j];
+                accelerations[j] += field * -self.masses[i];
+            }
+        }
+        accelerations
+    }
+}
+
+/// Gravitational acceleration towards a unit mass at `offset`
+#[inline]
+fn field<S: Space>(offset: S, softening: f64) -> S {
+    let distance_squared = offset.norm_squared() + softening * softening;
+    if distance_squared == 0.0 {
+        return S::default();
+    }
+    offset * (G.value.value / (distance_squared * distance_squared.sqrt()))
+}
+
+#[inline]
+fn offset<S: Space>(values: &[S], rates: &[S], dt: f64) -> Vec<S> {
+    values
+        .iter()
+        .zip(rates)
+        .map(|(&value, &rate)| value + rate * dt)
+        .collect()
+}
+
+/// A quadtree or octree of bodies for the Barnes–Hut approximation
+struct Tree<'a, S: Space> {
+    nodes: Vec<Node<S>>,
+    positions: &'a [S],
+    masses: &'a [f64],
+}
+
+struct Node<S> {
+    center: S,
+    half_size: f64,
+    mass: f64,
+    /// Sum of mass times position, for the centre of mass
+    moment: S,
+    /// Index of the first of `Space::CELLS` consecutive children
+    children: Option<usize>,
+    bodies: Vec<usize>,
+}
+
+impl<'a, S: Space> Tree<'a, S> {
+    /// Cells stop splitting at this depth so that coincident bodies share a leaf
+    const MAX_DEPTH: usize = 32;
+
+    fn new(positions: &'a [S], masses: &'a [f64]) -> Self {
+        let count = positions.len().max(1) as f64;
+        let center = positions.iter().fold(S::default(), |sum, &p| sum + p) / count;
+        let half_size = positions
+            .iter()
+            .map(|&p| (p - center).max_norm())
+            .fold(0.0, f64::max)
+            * (1.0 + 1e-9)
+            + f64::MIN_POSITIVE;
+
+        let mut tree = Self {
+            nodes: vec![Node::new(center, half_size)],
+            positions,
+            masses,
+        };
+        for body in 0..positions.len() {
+            tree.insert(0, body, 0);
+        }
+        tree
+    }
+
+    fn insert(&mut self, node: usize, body: usize, depth: usize) {
+        let position = self.positions[body];
+        let mass = self.masses[body];
+
+        self.nodes[node].mass += mass;
+        self.nodes[node].moment += position * mass;
+
+        if let Some(first) = self.nodes[node].children {
+            let cell = position.cell(self.nodes[node].center);
+            self.insert(first + cell, body, depth + 1);
+            return;
+        }
+
+        self.nodes[node].bodies.push(body);
+        if self.nodes[node].bodies.len() == 1 || depth >= Self::MAX_DEPTH {
+            return;
+        }
+
+        let center = self.nodes[node].center;
+        let half_size = self.nodes[node].half_size;
+        let first = self.nodes.len();
+        for cell in 0..S::CELLS {
+            let child = Node::new(S::cell_center(center, half_size, cell), half_size / 2.0);
+            self.nodes.push(child);
+        }
+
+        let bodies = std::mem::take(&mut self.nodes[node].bodies);
+        self.nodes[node].children = Some(first);
+        for body in bodies {
+            let cell = self.positions[body].cell(center);
+            self.insert(first + cell, body, depth + 1);
+        }
+    }
+
+    fn acceleration(&self, body: usize, opening_angle: f64, softening: f64) -> S {
+        self.node_acceleration(0, body, opening_angle, softening)
+    }
+
+    fn node_acceleration(&self, node: usize, body: usize, opening_angle: f64, softening: f64) -> S {
+        let n = &self.nodes[node];
+        let position = self.positions[body];
+
+        if n.mass == 0.0 {
+            return S::default();
+        }
+
+        let first = match n.children {
+            Some(first) => first,
+            None => {
+                return n.bodies.iter().filter(|&&other| other != body).fold(
+                    S::default(),
+                    |sum, &other| {
+                        let offset = self.positions[other] - position;
+                        sum + field(offset, softening) * self.masses[other]
+                    },
+                )
+            }
+        };
+
+        let offset = n.moment / n.mass - position;
+        let size = 2.0 * n.half_size;
+        let contains_body = (position - n.center).max_norm() <= n.half_size;
+
+        if !contains_body && size * size < opening_angle * opening_angle * offset.norm_squared() {
+            return field(offset, softening) * n.mass;
+        }
+
+        (first..first + S::CELLS).fold(S::default(), |sum, child| {
+            sum + self.node_acceleration(child, body, opening_angle, softening)
+        })
+    }
+}
+
+impl<S: Space> Node<S> {
+    #[inline]
+    fn new(center: S, half_size: f64) -> Self {
+        Self {
+            center,
+            half_size,
+            mass: 0.0,
+            moment: S::default(),
+            children: None,
+            bodies: vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::catalogue::{EARTH, SUN};
+    use crate::{Sqrt, Squared, AU};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    fn sun_and_earth() -> [PointMass2; 2] {
+        let speed = (G * SUN.mass / AU).sqrt();
+        [
+            PointMass {
+                mass: SUN.mass,
+                position: Position::default(),
+                velocity: Vector2::in_m_per_s(0.0, -speed.value * EARTH.mass / SUN.mass),
+            },
+            PointMass {
+                mass: EARTH.mass,
+                position: Position::in_m(AU.value, 0.0),
+                velocity: Vector2::in_m_per_s(0.0, speed.value),
+            },
+        ]
+    }
+
+    #[test]
+    fn two_body_orbit_conserves_energy_and_momentum() {
+        for integrator in [
+            Integrator::Leapfrog,
+            Integrator::VelocityVerlet,
+            Integrator::RungeKutta4,
+        ] {
+            let mut simulation = NBody::new(sun_and_earth()).with_integrator(integrator);
+            let start = simulation.body(1).position;
+
+            let steps = 365;
+            let dt = Duration::of_orbit(AU, SUN.mass + EARTH.mass) / steps as f64;
+            for _ in 0..steps {
+                simulation.step(dt);
+            }
+
+            let energy = simulation.energy_drift() / simulation.energy();
+            let earth = simulation.body(1);
+            let momentum =
+                simulation.momentum_drift().magnitude() / (earth.mass * earth.velocity.magnitude());
+            let returned = (simulation.body(1).position - start).magnitude() / AU;
+
+            assert!(energy.abs() < 1e-6, "{:?}: {}", integrator, energy);
+            assert!(momentum < 1e-12, "{:?}: {}", integrator, momentum);
+            assert!(returned < 1e-3, "{:?}: {}", integrator, returned);
+        }
+    }
+
+    #[test]
+    fn barnes_hut_matches_pairwise() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let bodies = (0..300)
+            .map(|_| PointMass3 {
+                mass: Mass::in_kg(rng.gen_range(1e20..1e22)),
+                position: Position3::in_au(
+                    rng.gen_range(-1.0..1.0),
+                    rng.gen_range(-1.0..1.0),
+                    rng.gen_range(-1.0..1.0),
+                ),
+                velocity: Vector3::default(),
+            })
+            .collect::<Vec<_>>();
+
+        let exact = NBody::new(bodies.clone()).accelerations();
+        let approximate = NBody::new(bodies).with_barnes_hut(0.5).accelerations();
+
+        for (exact, approximate) in exact.iter().zip(&approximate) {
+            let error = (*exact - *approximate).magnitude() / exact.magnitude();
+            assert!(error < 0.05, "{}", error);
+        }
+
+        let exact = NBody::new(sun_and_earth()).accelerations();
+        let tree = NBody::new(sun_and_earth())
+            .with_barnes_hut(0.0)
+            .accelerations();
+        for (exact, tree) in exact.iter().zip(&tree) {
+            assert!((*exact - *tree).magnitude() / exact.magnitude() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn softening_limits_close_encounters() {
+        let body = |x: f64| PointMass2 {
+            mass: SUN.mass,
+            position: Position::in_m(x, 0.0),
+            velocity: Vector2::default(),
+        };
+        let bodies = [body(0.0), body(1.0)];
+
+        let hard = NBody::new(bodies).accelerations()[0].magnitude();
+        let soft = NBody::new(bodies).with_softening(AU).accelerations()[0].magnitude();
+
+        let bound = G * SUN.mass / AU.squared();
+        assert!(hard > bound);
+        assert!(soft < bound);
+        assert_eq!(
+            Vector2::default(),
+            NBody::new([body(0.0), body(0.0)]).accelerations()[0]
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use crate::constants::G;
+use crate::{
+    Angle, AngularSpeed, Distance, Duration, GravitationalParameter, Length, Mass, Polar, Speed,
+    Sqrt, TimeIndex, Vector2,
+};
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+/// An elliptical Keplerian orbit in the plane around a central mass.
+///
+/// Positions and velocities are relative to the central body.
+/// https://en.wikipedia.org/wiki/Kepler_orbit
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Orbit {
+    pub semi_major_axis: Length,
+    /// In the range `0..1`, where zero is a circle
+    pub eccentricity: f64,
+    /// Angle from the positive x-axis to the periapsis
+    pub argument_of_periapsis: Angle,
+    /// A time at which the body passes through periapsis
+    pub epoch: TimeIndex,
+    pub central_mass: Mass,
+}
+
+impl Orbit {
+    #[inline]
+    pub fn new(
+        semi_major_axis: Length,
+        eccentricity: f64,
+        argument_of_periapsis: Angle,
+        epoch: TimeIndex,
+        central_mass: Mass,
+    ) -> Self {
+        debug_assert!(semi_major_axis > Length::zero());
+        debug_assert!((0.0..1.0).contains(&eccentricity));
+
+        Self {
+            semi_major_axis,
+            eccentricity,
+            argument_of_periapsis,
+            epoch,
+            central_mass,
+        }
+    }
+
+    #[inline]
+    pub fn circular(radius: Length, epoch: TimeIndex, central_mass: Mass) -> Self {
+        Self::new(radius, 0.0, Angle::zero(), epoch, central_mass)
+    }
+
+    #[inline]
+    pub fn gravitational_parameter(&self) -> GravitationalParameter {
+        G * self.central_mass
+    }
+
+    #[inline]
+    pub fn period(&self) -> Duration {
+        Duration::of_orbit(self.semi_major_axis, self.central_mass)
+    }
+
+    /// Average angular speed over one orbit
+    #[inline]
+    pub fn mean_motion(&self) -> AngularSpeed {
+        Angle::TAU / self.period()
+    }
+
+    /// Closest distance to the central body
+    #[inline]
+    pub fn periapsis(&self) -> Length {
+        self.semi_major_axis * (1.0 - self.eccentricity)
+    }
+
+    /// Farthest distance from the central body
+    #[inline]
+    pub fn apoapsis(&self) -> Length {
+        self.semi_major_axis * (1.0 + self.eccentricity)
+    }
+
+    /// Semi-latus rectum, `a(1 - e²)`
+    #[inline]
+    pub fn semi_latus_rectum(&self) -> Length {
+        self.semi_major_axis * (1.0 - self.eccentricity * self.eccentricity)
+    }
+
+    /// In the range `0..TAU`
+    #[inline]
+    pub fn mean_anomaly(&self, time: TimeIndex) -> Angle {
+        let mean_anomaly = self.mean_motion() * (time - self.epoch);
+        Angle::in_rad(mean_anomaly.value.rem_euclid(TAU))
+    }
+
+    /// Solves Kepler's equation `M = E - e sin E` for `E` by Newton's method
+    pub fn eccentric_anomaly(&self, time: TimeIndex) -> Angle {
+        let m = self.mean_anomaly(time).value;
+        let e = self.eccentricity;
+
+        let mut anomaly = if e < 0.8 { m } else { PI };
+        for _ in 0..Self::MAX_ITERATIONS {
+            let step = (anomaly - e * anomaly.sin() - m) / (1.0 - e * anomaly.cos());
+            anomaly -= step;
+
+            if step.abs() < Self::TOLERANCE {
+                break;
+            }
+        }
+
+        Angle::in_rad(anomaly)
+    }
+
+    /// Angle from the periapsis to the body, as seen from the central body
+    #[inline]
+    pub fn true_anomaly(&self, time: TimeIndex) -> Angle {
+        let e = self.eccentricity;
+        let half = self.eccentric_anomaly(time) / 2.0;
+
+        let y = (1.0 + e).sqrt() * half.sin();
+        let x = (1.0 - e).sqrt() * half.cos();
+        Angle::atan2(y, x) * 2.0
+    }
+
+    /// Distance from the central body and angle from the positive x-axis
+    #[inline]
+    pub fn polar(&self, time: TimeIndex) -> Polar<Length> {
+        let true_anomaly = self.true_anomaly(time);
+        Polar {
+            magnitude: self.radius_at(true_anomaly),
+            angle: self.argument_of_periapsis + true_anomaly,
+        }
+    }
+
+    #[inline]
+    pub fn position(&self, time: TimeIndex) -> Distance {
+        self.polar(time).euclidean()
+    }
+
+    pub fn velocity(&self, time: TimeIndex) -> Vector2<Speed> {
+        let true_anomaly = self.true_anomaly(time);
+        let angle = self.argument_of_periapsis + true_anomaly;
+
+        let e = self.eccentricity;
+        let speed = (self.gravitational_parameter() / self.semi_latus_rectum()).sqrt();
+        let radial = speed * (e * true_anomaly.sin());
+        let transverse = speed * (1.0 + e * true_anomaly.cos());
+
+        Vector2::from_angle_and_magnitude(angle, radial)
+            + Vector2::from_angle_and_magnitude(angle + Angle::in_rad(FRAC_PI_2), transverse)
+    }
+
+    #[inline]
+    fn radius_at(&self, true_anomaly: Angle) -> Length {
+        self.semi_latus_rectum() / (1.0 + self.eccentricity * true_anomaly.cos())
+    }
+
+    const MAX_ITERATIONS: usize = 50;
+
+    const TOLERANCE: f64 = 1e-12;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::catalogue::SUN;
+    use crate::{Squared, AU, DEG};
+
+    fn earth() -> Orbit {
+        Orbit::new(AU, 0.0167, 102.9 * DEG, TimeIndex::default(), SUN.mass)
+    }
+
+    fn assert_close(expected: Length, actual: Length) {
+        assert!(
+            (expected - actual).abs() < Length::in_m(1.0),
+            "{:?} != {:?}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn circular_orbit_matches_existing_helpers() {
+        let orbit = Orbit::circular(AU, TimeIndex::default(), SUN.mass);
+        let speed = (orbit.gravitational_parameter() / AU).sqrt();
+        let quarter = TimeIndex::default() + orbit.period() / 4.0;
+
+        assert_close(AU, orbit.position(quarter).y);
+        assert_eq!(AngularSpeed::of_orbit(SUN.mass, AU), orbit.mean_motion());
+        assert!((orbit.velocity(quarter).magnitude() - speed).abs() < Speed::in_m_per_s(1e-6));
+    }
+
+    #[test]
+    fn periapsis_and_apoapsis() {
+        let orbit = earth();
+        let periapsis =
+            Vector2::from_angle_and_magnitude(orbit.argument_of_periapsis, orbit.periapsis());
+        let half = orbit.epoch + orbit.period() / 2.0;
+
+        assert_close(periapsis.x, orbit.position(orbit.epoch).x);
+        assert_close(periapsis.y, orbit.position(orbit.epoch).y);
+        assert_close(orbit.apoapsis(), orbit.position(half).magnitude());
+        assert_close(
+            orbit.periapsis(),
+            orbit.position(orbit.epoch + orbit.period()).magnitude(),
+        );
+    }
+
+    #[test]
+    fn vis_viva_and_angular_momentum() {
+        let orbit = earth();
+        let mu = orbit.gravitational_parameter();
+
+        let momentum = |time| {
+            let r = orbit.position(time);
+            let v = orbit.velocity(time);
+            r.x.value * v.y.value - r.y.value * v.x.value
+        };
+        let h = momentum(orbit.epoch);
+
+        for days in [10.0, 100.0, 200.0, 300.0] {
+            let time = orbit.epoch + Duration::in_d(days);
+            let r = orbit.position(time).magnitude();
+            let v = orbit.velocity(time).magnitude();
+
+            let expected = mu / r * 2.0 - mu / orbit.semi_major_axis;
+            assert!(((v.squared() - expected) / expected).abs() < 1e-9);
+            assert!(((momentum(time) - h) / h).abs() < 1e-9);
+        }
+    }
+}
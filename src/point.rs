@@ -0,0 +1,257 @@
+use std::ops::{Add, AddAssign, Div, Mul, Sub, SubAssign};
+
+/// A point in an affine space whose differences are of type `D`.
+///
+/// Points can be offset by a `D` and subtracted from each other to give a `D`,
+/// but cannot be added together or scaled.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Point<D> {
+    /// Offset from the origin
+    pub value: D,
+}
+
+impl<D> Point<D> {
+    #[inline]
+    pub const fn from_origin(value: D) -> Self {
+        Self { value }
+    }
+}
+
+impl<D> Point<D>
+where
+    D: Add<D, Output = D> + Sub<D, Output = D> + Mul<f64, Output = D> + Copy,
+{
+    /// Linear interpolation between two points, where `t = 0` returns `self` and `t = 1` returns `rhs`
+    #[inline]
+    pub fn lerp(self, rhs: Self, t: f64) -> Self {
+        self + (rhs - self) * t
+    }
+
+    #[inline]
+    pub fn midpoint(self, rhs: Self) -> Self {
+        self.lerp(rhs, 0.5)
+    }
+}
+
+impl<D> Point<D>
+where
+    D: Add<D, Output = D> + Sub<D, Output = D> + Mul<f64, Output = D> + Div<f64, Output = D>,
+    D: Default + Copy,
+{
+    /// Weighted average of the given points, with the weights normalized to sum to one.
+    /// Returns `None` if there are no points or the weights sum to zero.
+    /// https://en.wikipedia.org/wiki/Affine_space#Barycentric_coordinates
+    pub fn barycentric<I: IntoIterator<Item = (Self, f64)>>(points: I) -> Option<Self> {
+        let mut points = points.into_iter();
+        let (first, first_weight) = points.next()?;
+
+        let mut offset = D::default();
+        let mut total_weight = first_weight;
+        for (point, weight) in points {
+            offset = offset + (point - first) * weight;
+            total_weight += weight;
+        }
+
+        if total_weight == 0.0 {
+            None
+        } else {
+            Some(first + offset / total_weight)
+        }
+    }
+}
+
+#[rustfmt::skip]
+impl<D> const From<D> for Point<D> {
+    #[inline]
+    fn from(value: D) -> Self {
+        Self { value }
+    }
+}
+
+impl<D: Add<D, Output = D>> Add<D> for Point<D> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: D) -> Self {
+        Self::from_origin(self.value + rhs)
+    }
+}
+
+impl<D: Add<D, Output = D> + Copy> Add<&D> for Point<D> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: &D) -> Self {
+        self + *rhs
+    }
+}
+
+impl<D: Add<D, Output = D> + Copy> Add<D> for &Point<D> {
+    type Output = Point<D>;
+    #[inline]
+    fn add(self, rhs: D) -> Point<D> {
+        *self + rhs
+    }
+}
+
+impl<D: Add<D, Output = D> + Copy> Add<&D> for &Point<D> {
+    type Output = Point<D>;
+    #[inline]
+    fn add(self, rhs: &D) -> Point<D> {
+        *self + *rhs
+    }
+}
+
+impl<D: AddAssign> AddAssign<D> for Point<D> {
+    #[inline]
+    fn add_assign(&mut self, rhs: D) {
+        self.value += rhs;
+    }
+}
+
+impl<D: AddAssign + Copy> AddAssign<&D> for Point<D> {
+    #[inline]
+    fn add_assign(&mut self, rhs: &D) {
+        self.value += *rhs;
+    }
+}
+
+impl<D: Sub<D, Output = D>> Sub<D> for Point<D> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: D) -> Self {
+        Self::from_origin(self.value - rhs)
+    }
+}
+
+impl<D: Sub<D, Output = D> + Copy> Sub<&D> for Point<D> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: &D) -> Self {
+        self - *rhs
+    }
+}
+
+impl<D: Sub<D, Output = D> + Copy> Sub<D> for &Point<D> {
+    type Output = Point<D>;
+    #[inline]
+    fn sub(self, rhs: D) -> Point<D> {
+        *self - rhs
+    }
+}
+
+impl<D: Sub<D, Output = D> + Copy> Sub<&D> for &Point<D> {
+    type Output = Point<D>;
+    #[inline]
+    fn sub(self, rhs: &D) -> Point<D> {
+        *self - *rhs
+    }
+}
+
+impl<D: SubAssign> SubAssign<D> for Point<D> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: D) {
+        self.value -= rhs;
+    }
+}
+
+impl<D: SubAssign + Copy> SubAssign<&D> for Point<D> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: &D) {
+        self.value -= *rhs;
+    }
+}
+
+impl<D: Sub<D, Output = D>> Sub for Point<D> {
+    type Output = D;
+    #[inline]
+    fn sub(self, rhs: Self) -> D {
+        self.value - rhs.value
+    }
+}
+
+impl<D: Sub<D, Output = D> + Copy> Sub<&Point<D>> for Point<D> {
+    type Output = D;
+    #[inline]
+    fn sub(self, rhs: &Self) -> D {
+        self.value - rhs.value
+    }
+}
+
+impl<D: Sub<D, Output = D> + Copy> Sub<Point<D>> for &Point<D> {
+    type Output = D;
+    #[inline]
+    fn sub(self, rhs: Point<D>) -> D {
+        self.value - rhs.value
+    }
+}
+
+impl<D: Sub<D, Output = D> + Copy> Sub<&Point<D>> for &Point<D> {
+    type Output = D;
+    #[inline]
+    fn sub(self, rhs: &Point<D>) -> D {
+        self.value - rhs.value
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Duration, Position, TimeIndex, Vector2};
+
+    #[test]
+    fn point_sub_point() {
+        let a = Position::in_m(3.0, 5.0);
+        let b = Position::in_m(1.0, 1.0);
+        let d = Vector2::in_m(2.0, 4.0);
+
+        assert_eq!(d, a - b);
+        assert_eq!(d, a - &b);
+        assert_eq!(d, &a - b);
+        assert_eq!(d, &a - &b);
+    }
+
+    #[test]
+    fn point_add_sub_offset() {
+        let a = TimeIndex::in_s(10.0);
+        let b = TimeIndex::in_s(12.0);
+        let d = Duration::in_s(2.0);
+
+        assert_eq!(b, a + d);
+        assert_eq!(b, &a + &d);
+        assert_eq!(a, b - d);
+        assert_eq!(a, &b - &d);
+
+        let mut c = a;
+        c += d;
+        assert_eq!(b, c);
+        c -= &d;
+        assert_eq!(a, c);
+    }
+
+    #[test]
+    fn midpoint_and_lerp() {
+        let a = Position::in_m(0.0, 0.0);
+        let b = Position::in_m(4.0, 8.0);
+
+        assert_eq!(Position::in_m(2.0, 4.0), a.midpoint(b));
+        assert_eq!(Position::in_m(1.0, 2.0), a.lerp(b, 0.25));
+        assert_eq!(Position::in_m(8.0, 16.0), a.lerp(b, 2.0));
+    }
+
+    #[test]
+    fn barycentric() {
+        let a = Position::in_m(0.0, 0.0);
+        let b = Position::in_m(3.0, 0.0);
+        let c = Position::in_m(0.0, 3.0);
+
+        let centroid = Point::barycentric([(a, 1.0), (b, 1.0), (c, 1.0)]);
+        assert_eq!(Some(Position::in_m(1.0, 1.0)), centroid);
+
+        let weighted = Point::barycentric([(a, 2.0), (b, 6.0)]);
+        assert_eq!(Some(Position::in_m(2.25, 0.0)), weighted);
+
+        assert_eq!(None, Point::barycentric([(a, 1.0), (b, -1.0)]));
+        assert_eq!(None, Point::<Duration>::barycentric(std::iter::empty()));
+    }
+}
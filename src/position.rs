@@ -1,13 +1,13 @@
-use crate::{Distance, Vector2};
-use std::ops::{Add, AddAssign, Sub, SubAssign};
+use crate::{Distance, Length, Point, Spherical, Vector2, Vector3, AU, LY};
 
-#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
-pub struct Position(Distance);
+pub type Position = Point<Distance>;
+
+pub type Position3 = Point<Vector3<Length>>;
 
 impl Position {
     #[inline]
     pub const fn in_m(x: f64, y: f64) -> Self {
-        Self(Vector2::in_m(x, y))
+        Self::from_origin(Vector2::in_m(x, y))
     }
 
     #[inline]
@@ -18,134 +18,99 @@ impl Position {
     const M_PER_LY: f64 = 9.460_730_472_580_8e15;
 }
 
-#[rustfmt::skip]
-impl const From<Distance> for Position {
+impl From<Position> for Distance {
     #[inline]
-    fn from(value: Distance) -> Self {
-        Self(value)
+    fn from(value: Position) -> Self {
+        value.value
     }
 }
 
-impl Add<Distance> for Position {
-    type Output = Position;
+impl Position3 {
     #[inline]
-    fn add(self, rhs: Distance) -> Self::Output {
-        self.0.add(rhs).into()
+    pub const fn in_m(x: f64, y: f64, z: f64) -> Self {
+        Self::from_origin(Vector3::in_m(x, y, z))
     }
-}
 
-impl Add<&Distance> for Position {
-    type Output = Position;
     #[inline]
-    fn add(self, rhs: &Distance) -> Self::Output {
-        self.0.add(rhs).into()
+    pub const fn in_ly(x: f64, y: f64, z: f64) -> Self {
+        Self::in_m(x * LY.value, y * LY.value, z * LY.value)
     }
-}
 
-impl Add<Distance> for &Position {
-    type Output = Position;
     #[inline]
-    fn add(self, rhs: Distance) -> Self::Output {
-        self.0.add(rhs).into()
+    pub const fn in_au(x: f64, y: f64, z: f64) -> Self {
+        Self::in_m(x * AU.value, y * AU.value, z * AU.value)
     }
-}
 
-impl Add<&Distance> for &Position {
-    type Output = Position;
+    /// Drops the z-coordinate
     #[inline]
-    fn add(self, rhs: &Distance) -> Self::Output {
-        self.0.add(rhs).into()
+    pub fn project_xy(self) -> Position {
+        Position::from_origin(self.value.project_xy())
     }
-}
 
-impl AddAssign<Distance> for Position {
+    /// Returns the position relative to `origin` in spherical coordinates
     #[inline]
-    fn add_assign(&mut self, rhs: Distance) {
-        self.0.add_assign(rhs);
+    pub fn to_spherical(self, origin: Self) -> Spherical<Length> {
+        Spherical::from_vector(self - origin)
     }
-}
 
-impl AddAssign<&Distance> for Position {
     #[inline]
-    fn add_assign(&mut self, rhs: &Distance) {
-        self.0.add_assign(rhs)
+    pub fn from_spherical(origin: Self, spherical: Spherical<Length>) -> Self {
+        origin + spherical.vector()
     }
 }
 
-impl Sub<Distance> for Position {
-    type Output = Position;
+/// Places the position in the xy-plane
+impl From<Position> for Position3 {
     #[inline]
-    fn sub(self, rhs: Distance) -> Self::Output {
-        self.0.sub(rhs).into()
+    fn from(value: Position) -> Self {
+        Self::from_origin(value.value.into())
     }
 }
 
-impl Sub<&Distance> for Position {
-    type Output = Position;
+impl From<Position3> for Vector3<Length> {
     #[inline]
-    fn sub(self, rhs: &Distance) -> Self::Output {
-        self.0.sub(rhs).into()
+    fn from(value: Position3) -> Self {
+        value.value
     }
 }
 
-impl Sub<Distance> for &Position {
-    type Output = Position;
-    #[inline]
-    fn sub(self, rhs: Distance) -> Self::Output {
-        self.0.sub(rhs).into()
-    }
-}
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Angle;
 
-impl Sub<&Distance> for &Position {
-    type Output = Position;
-    #[inline]
-    fn sub(self, rhs: &Distance) -> Self::Output {
-        self.0.sub(rhs).into()
-    }
-}
+    #[test]
+    fn position_projection() {
+        let position = Position::in_m(1.0, 2.0);
+        let position3 = Position3::from(position);
 
-impl SubAssign<Distance> for Position {
-    #[inline]
-    fn sub_assign(&mut self, rhs: Distance) {
-        self.0.sub_assign(rhs);
+        assert_eq!(Position3::in_m(1.0, 2.0, 0.0), position3);
+        assert_eq!(position, position3.project_xy());
+        assert_eq!(position, Position3::in_m(1.0, 2.0, 3.0).project_xy());
     }
-}
 
-impl SubAssign<&Distance> for Position {
-    #[inline]
-    fn sub_assign(&mut self, rhs: &Distance) {
-        self.0.sub_assign(rhs);
-    }
-}
+    #[test]
+    fn spherical_round_trip() {
+        let origin = Position3::in_au(1.0, 0.0, 0.0);
+        let position = Position3::in_au(1.0, 1.0, 1.0);
 
-impl Sub for Position {
-    type Output = Distance;
-    #[inline]
-    fn sub(self, rhs: Position) -> Distance {
-        self.0.sub(rhs.0)
-    }
-}
+        let spherical = position.to_spherical(origin);
 
-impl Sub<&Position> for Position {
-    type Output = Distance;
-    #[inline]
-    fn sub(self, rhs: &Position) -> Distance {
-        self.0.sub(rhs.0)
-    }
-}
+        assert!((spherical.magnitude - 2f64.sqrt() * AU).abs() < Length::in_m(1e-3));
+        assert!((spherical.phi - Angle::PI / 4.0).abs() < Angle::in_rad(1e-12));
+        assert!((spherical.theta - Angle::PI / 2.0).abs() < Angle::in_rad(1e-12));
 
-impl Sub<Position> for &Position {
-    type Output = Distance;
-    #[inline]
-    fn sub(self, rhs: Position) -> Distance {
-        self.0.sub(rhs.0)
+        let round_trip = Position3::from_spherical(origin, spherical);
+
+        assert!((round_trip - position).magnitude() < Length::in_m(1e-3));
     }
-}
 
-impl Sub<&Position> for &Position {
-    type Output = Distance;
-    #[inline]
-    fn sub(self, rhs: &Position) -> Distance {
-        self.0.sub(rhs.0)
+    #[test]
+    fn spherical_of_origin() {
+        let origin = Position3::in_m(1.0, 2.0, 3.0);
+        let spherical = origin.to_spherical(origin);
+
+        assert_eq!(Length::zero(), spherical.magnitude);
+        assert_eq!(Angle::zero(), spherical.phi);
     }
 }
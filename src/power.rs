@@ -1,9 +1,8 @@
+use crate::constants::SIGMA;
 use crate::time::Frequency;
-use crate::{Area, Duration, Energy, Force, Radius, Speed, Squared, Temperature};
-
-/// https://en.wikipedia.org/wiki/Stefan%E2%80%93Boltzmann_law
-/// Units: W / m2 / K4
-const SIGMA: f64 = 5.670374e-8;
+use crate::{
+    Area, Duration, Energy, Force, Radius, Speed, Squared, Temperature, TemperatureFourth,
+};
 
 scalar! {
     struct Power(f64) {
@@ -16,10 +15,7 @@ impl Power {
     /// Divide by the distance squared to determine the flux density
     /// https://en.wikipedia.org/wiki/Black-body_radiation
     pub fn blackbody(temp: Temperature, radius: Radius) -> Self {
-        let t_sqr = temp.value * temp.value;
-        let t_qrt = t_sqr * t_sqr;
-        let r_sqr = radius.value * radius.value;
-        Power::in_watts(t_qrt * r_sqr * SIGMA)
+        FluxDensity::blackbody(temp) * radius.squared()
     }
 }
 
@@ -35,15 +31,21 @@ scalar! {
 
 scalar_div!(Power | Area = FluxDensity);
 
+scalar! {
+    struct StefanBoltzmannConstant(f64) {
+        fn in_w_per_m2_k4(watts_per_meter_squared_kelvin_fourth) -> Self;
+    }
+}
+
+scalar_div!(FluxDensity | TemperatureFourth = StefanBoltzmannConstant);
+
 impl FluxDensity {
     pub fn in_orbit(star_temp: Temperature, star_radius: Radius, orbit_radius: Radius) -> Self {
         Power::blackbody(star_temp, star_radius) / orbit_radius.squared()
     }
 
     pub fn blackbody(temp: Temperature) -> Self {
-        let t_sqr = temp.value * temp.value;
-        let t_qrt = t_sqr * t_sqr;
-        FluxDensity::in_w_per_m2(SIGMA * t_qrt)
+        SIGMA * temp.squared().squared()
     }
 }
 
@@ -60,8 +62,9 @@ mod test {
 
     #[test]
     fn flux_density_in_orbit() {
-        use crate::{AU, K, KM};
-        let fd = FluxDensity::in_orbit(5772.0 * K, 695.7e3 * KM, AU);
+        use crate::catalogue::SUN;
+        use crate::AU;
+        let fd = FluxDensity::in_orbit(SUN.temperature, SUN.radius, AU);
         assert!((fd.value - 1361.16).abs() < 0.1);
     }
 }
@@ -0,0 +1,263 @@
+use crate::constants::G_0;
+use crate::{Acceleration, Duration, Force, Mass, MassRate, Speed};
+
+// Thrust per unit weight of propellant burned per second, measured in seconds
+// https://en.wikipedia.org/wiki/Specific_impulse
+scalar! {
+    struct SpecificImpulse(f64) {
+        fn in_s(seconds) -> Self;
+    }
+}
+
+/// Effective speed of the exhaust relative to the rocket
+pub type ExhaustVelocity = Speed;
+
+scalar_div!(Force | Speed = MassRate);
+
+impl SpecificImpulse {
+    #[inline]
+    pub fn from_exhaust_velocity(exhaust_velocity: ExhaustVelocity) -> Self {
+        Self::in_s(exhaust_velocity / G_0.value / Duration::in_s(1.0))
+    }
+
+    #[inline]
+    pub fn exhaust_velocity(self) -> ExhaustVelocity {
+        G_0.value * Duration::in_s(self.value)
+    }
+}
+
+impl Speed {
+    /// The Tsiolkovsky rocket equation, `Δv = vₑ ln(m₀ / m₁)`
+    /// https://en.wikipedia.org/wiki/Tsiolkovsky_rocket_equation
+    #[inline]
+    pub fn of_rocket(exhaust_velocity: ExhaustVelocity, wet_mass: Mass, dry_mass: Mass) -> Self {
+        debug_assert!(wet_mass >= dry_mass);
+        exhaust_velocity * (wet_mass / dry_mass).ln()
+    }
+}
+
+impl Mass {
+    /// Propellant needed to give `dry_mass` a change in velocity of `delta_v`,
+    /// the inverse of the rocket equation
+    #[inline]
+    pub fn propellant_for(
+        delta_v: Speed,
+        exhaust_velocity: ExhaustVelocity,
+        dry_mass: Mass,
+    ) -> Self {
+        dry_mass * (delta_v / exhaust_velocity).exp_m1()
+    }
+}
+
+/// Ratio of thrust to weight in the given gravity, where above one can lift off
+#[inline]
+pub fn thrust_to_weight(thrust: Force, mass: Mass, gravity: Acceleration) -> f64 {
+    thrust / (mass * gravity)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Engine {
+    pub thrust: Force,
+    pub specific_impulse: SpecificImpulse,
+}
+
+impl Engine {
+    #[inline]
+    pub const fn new(thrust: Force, specific_impulse: SpecificImpulse) -> Self {
+        Self {
+            thrust,
+            specific_impulse,
+        }
+    }
+
+    #[inline]
+    pub fn from_mass_flow(thrust: Force, mass_flow: MassRate) -> Self {
+        Self::new(
+            thrust,
+            SpecificImpulse::from_exhaust_velocity(thrust / mass_flow),
+        )
+    }
+
+    #[inline]
+    pub fn exhaust_velocity(&self) -> ExhaustVelocity {
+        self.specific_impulse.exhaust_velocity()
+    }
+
+    /// Propellant burned per second at full thrust
+    #[inline]
+    pub fn mass_flow(&self) -> MassRate {
+        self.thrust / self.exhaust_velocity()
+    }
+
+    /// Time to burn `propellant` at full thrust
+    #[inline]
+    pub fn burn_time(&self, propellant: Mass) -> Duration {
+        propellant / self.mass_flow()
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Stage {
+    /// Mass of the stage without propellant, which is dropped when the stage is spent
+    pub dry_mass: Mass,
+    pub propellant: Mass,
+    pub engine: Engine,
+}
+
+impl Stage {
+    #[inline]
+    pub fn wet_mass(&self) -> Mass {
+        self.dry_mass + self.propellant
+    }
+}
+
+/// The performance of one stage of a `Vehicle`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StageBudget {
+    pub delta_v: Speed,
+    pub burn_time: Duration,
+    /// Mass of the vehicle when the stage ignites
+    pub initial_mass: Mass,
+    /// Mass of the vehicle when the stage burns out, before it is dropped
+    pub burnout_mass: Mass,
+    pub thrust: Force,
+}
+
+impl StageBudget {
+    /// Thrust-to-weight ratio at ignition
+    #[inline]
+    pub fn initial_thrust_to_weight(&self, gravity: Acceleration) -> f64 {
+        thrust_to_weight(self.thrust, self.initial_mass, gravity)
+    }
+}
+
+/// A rocket made of stages that fire in order, each dropped once it is spent
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Vehicle {
+    pub payload: Mass,
+    /// The first stage to fire comes first
+    pub stages: Vec<Stage>,
+}
+
+impl Vehicle {
+    #[inline]
+    pub fn new(payload: Mass) -> Self {
+        Self {
+            payload,
+            stages: vec![],
+        }
+    }
+
+    /// Adds a stage that fires after the existing stages
+    #[inline]
+    pub fn with_stage(mut self, stage: Stage) -> Self {
+        self.stages.push(stage);
+        self
+    }
+
+    #[inline]
+    pub fn wet_mass(&self) -> Mass {
+        self.payload + self.stages.iter().map(Stage::wet_mass).sum::<Mass>()
+    }
+
+    pub fn budget(&self) -> Vec<StageBudget> {
+        let mut mass = self.wet_mass();
+
+        self.stages
+            .iter()
+            .map(|stage| {
+                let initial_mass = mass;
+                let burnout_mass = initial_mass - stage.propellant;
+                mass = burnout_mass - stage.dry_mass;
+
+                StageBudget {
+                    delta_v: Speed::of_rocket(
+                        stage.engine.exhaust_velocity(),
+                        initial_mass,
+                        burnout_mass,
+                    ),
+                    burn_time: stage.engine.burn_time(stage.propellant),
+                    initial_mass,
+                    burnout_mass,
+                    thrust: stage.engine.thrust,
+                }
+            })
+            .collect()
+    }
+
+    #[inline]
+    pub fn delta_v(&self) -> Speed {
+        self.budget().iter().map(|stage| stage.delta_v).sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{KG, N, TONNE};
+
+    #[test]
+    fn rocket_equation_round_trip() {
+        let exhaust_velocity = SpecificImpulse::in_s(300.0).exhaust_velocity();
+        let dry_mass = 1000.0 * KG;
+        let propellant = 2000.0 * KG;
+
+        let delta_v = Speed::of_rocket(exhaust_velocity, dry_mass + propellant, dry_mass);
+
+        assert!((exhaust_velocity * 3f64.ln() - delta_v).abs() < Speed::in_m_per_s(1e-9));
+        assert!(
+            (propellant - Mass::propellant_for(delta_v, exhaust_velocity, dry_mass)).abs()
+                < KG * 1e-9
+        );
+        assert!(
+            (SpecificImpulse::from_exhaust_velocity(exhaust_velocity).value - 300.0).abs() < 1e-12
+        );
+    }
+
+    #[test]
+    fn engine_burn() {
+        let engine = Engine::from_mass_flow(1000.0 * N, MassRate::in_kg_per_s(0.5));
+
+        assert!(
+            (Speed::in_m_per_s(2000.0) - engine.exhaust_velocity()).abs() < Speed::in_m_per_s(1e-9)
+        );
+        assert!(
+            (Duration::in_s(200.0) - engine.burn_time(100.0 * KG)).abs() < Duration::in_s(1e-9)
+        );
+        assert_eq!(
+            2.0,
+            thrust_to_weight(20.0 * N, 2.0 * KG, Acceleration::in_m_per_s2(5.0))
+        );
+    }
+
+    #[test]
+    fn staged_budget() {
+        let engine = Engine::new(1e6 * N, SpecificImpulse::in_s(350.0));
+        let stage = |dry: f64, propellant: f64| Stage {
+            dry_mass: dry * TONNE,
+            propellant: propellant * TONNE,
+            engine,
+        };
+
+        let vehicle = Vehicle::new(TONNE)
+            .with_stage(stage(10.0, 90.0))
+            .with_stage(stage(1.0, 9.0));
+        let budget = vehicle.budget();
+        let ve = engine.exhaust_velocity();
+
+        assert_eq!(111.0 * TONNE, vehicle.wet_mass());
+        assert_eq!(2, budget.len());
+        assert_eq!(
+            Speed::of_rocket(ve, 111.0 * TONNE, 21.0 * TONNE),
+            budget[0].delta_v
+        );
+        assert_eq!(
+            Speed::of_rocket(ve, 11.0 * TONNE, 2.0 * TONNE),
+            budget[1].delta_v
+        );
+        assert_eq!(budget[0].delta_v + budget[1].delta_v, vehicle.delta_v());
+
+        let single = Vehicle::new(TONNE).with_stage(stage(11.0, 99.0));
+        assert!(vehicle.delta_v() > single.delta_v());
+    }
+}
@@ -0,0 +1,348 @@
+use crate::{Distance, Length, Mass, Orbit, Position, Speed, TimeIndex, Vector2};
+
+/// Identifies a node in a `SceneGraph`
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct NodeId(usize);
+
+/// How a node moves relative to its parent
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SceneMotion {
+    Fixed(Distance),
+    /// The orbit's central body is the parent
+    Orbit(Orbit),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SceneNode {
+    pub parent: Option<NodeId>,
+    pub mass: Mass,
+    pub motion: SceneMotion,
+}
+
+/// World position and velocity of a node
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WorldState {
+    pub position: Position,
+    pub velocity: Vector2<Speed>,
+}
+
+/// A free-moving object, such as a ship, whose state is relative to a node of a `SceneGraph`
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Orbiter {
+    pub parent: NodeId,
+    pub position: Distance,
+    pub velocity: Vector2<Speed>,
+}
+
+/// A hierarchy of bodies on rails, such as moons orbiting planets orbiting stars.
+///
+/// World states are resolved for all nodes at once and cached until the time changes.
+#[derive(Debug, Clone, Default)]
+pub struct SceneGraph {
+    nodes: Vec<SceneNode>,
+    states: Vec<WorldState>,
+    time: Option<TimeIndex>,
+}
+
+impl SceneGraph {
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a node, whose parent must already be in the graph
+    pub fn add(&mut self, parent: Option<NodeId>, mass: Mass, motion: SceneMotion) -> NodeId {
+        debug_assert!(parent.is_none_or(|parent| parent.0 < self.nodes.len()));
+
+        self.nodes.push(SceneNode {
+            parent,
+            mass,
+            motion,
+        });
+        self.time = None;
+        NodeId(self.nodes.len() - 1)
+    }
+
+    #[inline]
+    pub fn node(&self, id: NodeId) -> &SceneNode {
+        &self.nodes[id.0]
+    }
+
+    pub fn children(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.ids()
+            .filter(move |child| self.node(*child).parent == Some(id))
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    #[inline]
+    pub fn state(&mut self, id: NodeId, time: TimeIndex) -> WorldState {
+        self.update(time);
+        self.states[id.0]
+    }
+
+    #[inline]
+    pub fn position(&mut self, id: NodeId, time: TimeIndex) -> Position {
+        self.state(id, time).position
+    }
+
+    #[inline]
+    pub fn velocity(&mut self, id: NodeId, time: TimeIndex) -> Vector2<Speed> {
+        self.state(id, time).velocity
+    }
+
+    /// Unbounded for root nodes
+    pub fn sphere_of_influence(&self, id: NodeId) -> Option<Length> {
+        let node = self.node(id);
+        let parent = self.node(node.parent?);
+        let separation = match node.motion {
+            SceneMotion::Fixed(offset) => offset.magnitude(),
+            SceneMotion::Orbit(orbit) => orbit.semi_major_axis,
+        };
+
+        Some(Length::sphere_of_influence(
+            separation,
+            node.mass,
+            parent.mass,
+        ))
+    }
+
+    /// The deepest node whose sphere of influence contains `point`.
+    ///
+    /// Between several roots, the one with the strongest pull on the point is chosen.
+    pub fn dominant(&mut self, point: Position, time: TimeIndex) -> Option<NodeId> {
+        self.update(time);
+
+        let roots = self.ids().filter(|id| self.node(*id).parent.is_none());
+        let mut dominant = self.strongest(roots, point)?;
+
+        loop {
+            let within = self.children(dominant).filter(|child| {
+                let distance = (point - self.states[child.0].position).magnitude();
+                self.sphere_of_influence(*child)
+                    .is_some_and(|radius| distance < radius)
+            });
+
+            match self.strongest(within, point) {
+                Some(child) => dominant = child,
+                None => return Some(dominant),
+            }
+        }
+    }
+
+    /// Moves the orbiter to the frame of the dominant node at its position,
+    /// returning true if its parent changed.
+    ///
+    /// An orbiter whose parent is not in this graph is left unchanged.
+    pub fn reparent(&mut self, orbiter: &mut Orbiter, time: TimeIndex) -> bool {
+        if orbiter.parent.0 >= self.nodes.len() {
+            return false;
+        }
+
+        let parent = self.state(orbiter.parent, time);
+        let position = parent.position + orbiter.position;
+
+        let Some(dominant) = self.dominant(position, time) else {
+            return false;
+        };
+        if dominant == orbiter.parent {
+            return false;
+        }
+
+        let state = self.states[dominant.0];
+        orbiter.parent = dominant;
+        orbiter.position = position - state.position;
+        orbiter.velocity = parent.velocity + orbiter.velocity - state.velocity;
+        true
+    }
+
+    fn ids(&self) -> impl Iterator<Item = NodeId> {
+        (0..self.nodes.len()).map(NodeId)
+    }
+
+    fn strongest<I: Iterator<Item = NodeId>>(&self, ids: I, point: Position) -> Option<NodeId> {
+        let pull = |id: &NodeId| {
+            let distance = point - self.states[id.0].position;
+            self.node(*id).mass.value / distance.magnitude_squared().value
+        };
+
+        ids.max_by(|a, b| pull(a).total_cmp(&pull(b)))
+    }
+
+    /// Resolves every node in insertion order, which puts parents before their children
+    fn update(&mut self, time: TimeIndex) {
+        if self.time == Some(time) {
+            return;
+        }
+
+        self.states.clear();
+        for node in &self.nodes {
+            let (offset, velocity) = match node.motion {
+                SceneMotion::Fixed(offset) => (offset, Vector2::default()),
+                SceneMotion::Orbit(orbit) => (orbit.position(time), orbit.velocity(time)),
+            };
+
+            let state = match node.parent {
+                Some(parent) => {
+                    let parent = self.states[parent.0];
+                    WorldState {
+                        position: parent.position + offset,
+                        velocity: parent.velocity + velocity,
+                    }
+                }
+                None => WorldState {
+                    position: Position::from_origin(offset),
+                    velocity,
+                },
+            };
+            self.states.push(state);
+        }
+
+        self.time = Some(time);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::catalogue::{EARTH, MOON, SUN};
+    use crate::{Duration, AU, KM};
+
+    struct System {
+        graph: SceneGraph,
+        sun: NodeId,
+        earth: NodeId,
+        moon: NodeId,
+    }
+
+    fn system() -> System {
+        let epoch = TimeIndex::default();
+        let mut graph = SceneGraph::new();
+
+        let sun = graph.add(None, SUN.mass, SceneMotion::Fixed(Distance::in_m(1e9, 0.0)));
+        let earth = graph.add(
+            Some(sun),
+            EARTH.mass,
+            SceneMotion::Orbit(Orbit::circular(AU, epoch, SUN.mass)),
+        );
+        let moon = graph.add(
+            Some(earth),
+            MOON.mass,
+            SceneMotion::Orbit(Orbit::circular(384_400.0 * KM, epoch, EARTH.mass)),
+        );
+
+        System {
+            graph,
+            sun,
+            earth,
+            moon,
+        }
+    }
+
+    #[test]
+    fn world_states_compose() {
+        let System {
+            mut graph,
+            sun,
+            earth,
+            moon,
+        } = system();
+
+        for days in [0.0, 7.0, 100.0] {
+            let time = TimeIndex::default() + Duration::in_d(days);
+            let SceneMotion::Orbit(earth_orbit) = graph.node(earth).motion else {
+                unreachable!()
+            };
+            let SceneMotion::Orbit(moon_orbit) = graph.node(moon).motion else {
+                unreachable!()
+            };
+
+            let expected =
+                graph.position(sun, time) + earth_orbit.position(time) + moon_orbit.position(time);
+            let velocity = earth_orbit.velocity(time) + moon_orbit.velocity(time);
+
+            assert_eq!(expected, graph.position(moon, time));
+            assert_eq!(velocity, graph.velocity(moon, time));
+        }
+
+        assert_eq!(vec![earth], graph.children(sun).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn dominant_body() {
+        let System {
+            mut graph,
+            sun,
+            earth,
+            moon,
+        } = system();
+        let time = TimeIndex::default() + Duration::in_d(3.0);
+
+        let near = |graph: &mut SceneGraph, id, offset| graph.position(id, time) + offset;
+
+        let by_moon = near(&mut graph, moon, Distance::in_m(1000e3, 0.0));
+        let by_earth = near(&mut graph, earth, Distance::in_m(0.0, -200_000e3));
+        let deep_space = near(&mut graph, earth, Distance::in_m(0.0, -2_000_000e3));
+
+        assert_eq!(Some(moon), graph.dominant(by_moon, time));
+        assert_eq!(Some(earth), graph.dominant(by_earth, time));
+        assert_eq!(Some(sun), graph.dominant(deep_space, time));
+        assert_eq!(None, SceneGraph::new().dominant(by_moon, time));
+    }
+
+    #[test]
+    fn reparent_preserves_world_state() {
+        let System {
+            mut graph,
+            earth,
+            moon,
+            ..
+        } = system();
+        let time = TimeIndex::default() + Duration::in_d(10.0);
+
+        let offset = graph.position(moon, time) - graph.position(earth, time);
+        let mut orbiter = Orbiter {
+            parent: earth,
+            position: offset + Distance::in_m(5000e3, 0.0),
+            velocity: Vector2::in_m_per_s(100.0, 0.0),
+        };
+        let world = |graph: &mut SceneGraph, orbiter: &Orbiter| {
+            let parent = graph.state(orbiter.parent, time);
+            (
+                parent.position + orbiter.position,
+                parent.velocity + orbiter.velocity,
+            )
+        };
+        let (position, velocity) = world(&mut graph, &orbiter);
+
+        assert!(graph.reparent(&mut orbiter, time));
+        assert_eq!(moon, orbiter.parent);
+        assert!(!graph.reparent(&mut orbiter, time));
+
+        let (after, velocity_after) = world(&mut graph, &orbiter);
+        assert!((after - position).magnitude() < Length::in_m(1e-3));
+        assert!((velocity_after - velocity).magnitude() < Speed::in_m_per_s(1e-9));
+    }
+
+    #[test]
+    fn reparent_from_another_graph() {
+        let System { moon, .. } = system();
+        let mut orbiter = Orbiter {
+            parent: moon,
+            position: Distance::in_m(1000e3, 0.0),
+            velocity: Vector2::default(),
+        };
+        let before = orbiter;
+
+        assert!(!SceneGraph::new().reparent(&mut orbiter, TimeIndex::default()));
+        assert_eq!(before, orbiter);
+    }
+}
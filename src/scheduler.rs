@@ -0,0 +1,341 @@
+use crate::{Calendar, Duration, TimeIndex};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// Identifies a scheduled event so that it can be cancelled
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventId(u64);
+
+/// How often a scheduled event fires. Recurring periods are positive and finite.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "UncheckedRecurrence"))]
+pub enum Recurrence {
+    Once,
+    Every(Duration),
+}
+
+impl Recurrence {
+    #[inline]
+    fn is_valid_period(period: Duration) -> bool {
+        period > Duration::zero() && period.value.is_finite()
+    }
+}
+
+/// A `Recurrence` as read from a save, before its period is validated
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+enum UncheckedRecurrence {
+    Once,
+    Every(Duration),
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<UncheckedRecurrence> for Recurrence {
+    type Error = &'static str;
+
+    fn try_from(recurrence: UncheckedRecurrence) -> Result<Self, Self::Error> {
+        match recurrence {
+            UncheckedRecurrence::Once => Ok(Recurrence::Once),
+            UncheckedRecurrence::Every(period) if Recurrence::is_valid_period(period) => {
+                Ok(Recurrence::Every(period))
+            }
+            UncheckedRecurrence::Every(_) => Err("recurrence period must be positive and finite"),
+        }
+    }
+}
+
+/// An event waiting in a `Scheduler`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scheduled<E> {
+    pub id: EventId,
+    pub due: TimeIndex,
+    pub recurrence: Recurrence,
+    pub event: E,
+}
+
+/// An event that fell due during `Scheduler::advance`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Occurrence<E> {
+    pub id: EventId,
+    /// The latest time the event was due
+    pub time: TimeIndex,
+    /// Earlier occurrences of a recurring event that were skipped because the clock jumped
+    pub missed: u64,
+    pub event: E,
+}
+
+/// A priority queue of one-shot and recurring events keyed by `TimeIndex`.
+///
+/// With the `serde` feature the scheduler can be saved and restored with its pending events.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Scheduler<E> {
+    queue: BinaryHeap<Queued<E>>,
+    next_id: u64,
+}
+
+/// Orders scheduled events by due time, then by id, reversed so that `BinaryHeap` pops
+/// the earliest event first
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+struct Queued<E>(Scheduled<E>);
+
+impl<E> Default for Scheduler<E> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> Scheduler<E> {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            queue: BinaryHeap::new(),
+            next_id: 0,
+        }
+    }
+
+    #[inline]
+    pub fn at(&mut self, time: TimeIndex, event: E) -> EventId {
+        self.schedule(time, Recurrence::Once, event)
+    }
+
+    #[inline]
+    pub fn after(&mut self, now: TimeIndex, delay: Duration, event: E) -> EventId {
+        self.at(now + delay, event)
+    }
+
+    /// Fires at `first` and then once per `period`
+    ///
+    /// # Panics
+    /// If `period` is not positive and finite, since the event would never stop firing
+    #[inline]
+    pub fn every(&mut self, first: TimeIndex, period: Duration, event: E) -> EventId {
+        assert!(
+            Recurrence::is_valid_period(period),
+            "recurrence period must be positive and finite"
+        );
+        self.schedule(first, Recurrence::Every(period), event)
+    }
+
+    #[inline]
+    pub fn on_date<C: Calendar>(&mut self, calendar: &C, date: &C::Date, event: E) -> EventId {
+        self.at(calendar.time_index(date), event)
+    }
+
+    /// Returns false if the event has already fired or been cancelled
+    pub fn cancel(&mut self, id: EventId) -> bool {
+        let len = self.queue.len();
+        self.queue.retain(|queued| queued.0.id != id);
+        self.queue.len() != len
+    }
+
+    #[inline]
+    pub fn next_due(&self) -> Option<TimeIndex> {
+        self.queue.peek().map(|queued| queued.0.due)
+    }
+
+    /// Pending events in the order they will fire
+    pub fn pending(&self) -> Vec<&Scheduled<E>> {
+        let mut pending = self.queue.iter().collect::<Vec<_>>();
+        pending.sort_by(|a, b| b.cmp(a));
+        pending.into_iter().map(|queued| &queued.0).collect()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    fn schedule(&mut self, due: TimeIndex, recurrence: Recurrence, event: E) -> EventId {
+        let id = EventId(self.next_id);
+        self.next_id += 1;
+
+        self.queue.push(Queued(Scheduled {
+            id,
+            due,
+            recurrence,
+            event,
+        }));
+        id
+    }
+}
+
+impl<E: Clone> Scheduler<E> {
+    /// Removes and returns every event due at or before `now`, in the order they fell due.
+    ///
+    /// A recurring event fires once per call even if several periods have passed,
+    /// with the skipped periods reported in `Occurrence::missed`.
+    pub fn advance(&mut self, now: TimeIndex) -> Vec<Occurrence<E>> {
+        let mut occurrences = vec![];
+
+        while self.next_due().is_some_and(|due| due <= now) {
+            let Queued(mut scheduled) = self.queue.pop().unwrap();
+
+            match scheduled.recurrence {
+                Recurrence::Once => occurrences.push(Occurrence {
+                    id: scheduled.id,
+                    time: scheduled.due,
+                    missed: 0,
+                    event: scheduled.event,
+                }),
+                Recurrence::Every(period) => {
+                    let missed = ((now - scheduled.due) / period).floor();
+                    let time = scheduled.due + missed * period;
+
+                    occurrences.push(Occurrence {
+                        id: scheduled.id,
+                        time,
+                        missed: missed as u64,
+                        event: scheduled.event.clone(),
+                    });
+
+                    scheduled.due = time + period;
+                    self.queue.push(Queued(scheduled));
+                }
+            }
+        }
+
+        occurrences
+    }
+}
+
+impl<E> Ord for Queued<E> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        let due = self.0.due.value.value.total_cmp(&other.0.due.value.value);
+        due.then(self.0.id.cmp(&other.0.id)).reverse()
+    }
+}
+
+impl<E> PartialOrd for Queued<E> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<E> PartialEq for Queued<E> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<E> Eq for Queued<E> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{DAY, HR};
+
+    #[test]
+    fn one_shot_events_fire_in_order() {
+        let now = TimeIndex::default();
+        let mut scheduler = Scheduler::new();
+
+        scheduler.after(now, 2.0 * HR, "second");
+        scheduler.at(now + HR, "first");
+        let cancelled = scheduler.after(now, 3.0 * HR, "cancelled");
+
+        assert!(scheduler.cancel(cancelled));
+        assert!(!scheduler.cancel(cancelled));
+
+        let events = scheduler.advance(now + 4.0 * HR);
+        let events = events.iter().map(|o| o.event).collect::<Vec<_>>();
+
+        assert_eq!(vec!["first", "second"], events);
+        assert!(scheduler.is_empty());
+    }
+
+    #[test]
+    fn recurring_event_reports_missed() {
+        let start = TimeIndex::default();
+        let mut scheduler = Scheduler::new();
+        let id = scheduler.every(start + 30.0 * DAY, 30.0 * DAY, "payday");
+
+        assert!(scheduler.advance(start + 29.0 * DAY).is_empty());
+
+        let occurrences = scheduler.advance(start + 100.0 * DAY);
+
+        assert_eq!(
+            vec![Occurrence {
+                id,
+                time: start + 90.0 * DAY,
+                missed: 2,
+                event: "payday",
+            }],
+            occurrences
+        );
+        assert_eq!(Some(start + 120.0 * DAY), scheduler.next_due());
+    }
+
+    #[test]
+    fn pending_in_firing_order() {
+        let now = TimeIndex::default();
+        let mut scheduler = Scheduler::new();
+
+        scheduler.at(now + DAY, 'b');
+        scheduler.at(now + HR, 'a');
+        scheduler.every(now + 2.0 * DAY, DAY, 'c');
+
+        let pending = scheduler.pending();
+        let pending = pending.iter().map(|s| s.event).collect::<Vec<_>>();
+
+        assert_eq!(vec!['a', 'b', 'c'], pending);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde_round_trip() {
+        let now = TimeIndex::default();
+        let mut scheduler = Scheduler::new();
+
+        scheduler.at(now + DAY, "launch".to_string());
+        scheduler.every(now + HR, 6.0 * HR, "report".to_string());
+        let cancelled = scheduler.at(now + 2.0 * DAY, "cancelled".to_string());
+        scheduler.cancel(cancelled);
+
+        let json = serde_json::to_string(&scheduler).unwrap();
+        let mut restored: Scheduler<String> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(scheduler.pending(), restored.pending());
+        assert_eq!(
+            scheduler.at(now, "next".to_string()),
+            restored.at(now, "next".to_string())
+        );
+        assert_eq!(
+            scheduler.advance(now + 2.0 * DAY),
+            restored.advance(now + 2.0 * DAY)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "recurrence period must be positive and finite")]
+    fn zero_period_is_rejected() {
+        Scheduler::new().every(TimeIndex::default(), Duration::zero(), "never");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn zero_period_is_rejected_on_load() {
+        let json = serde_json::to_string(&Recurrence::Every(Duration::zero())).unwrap();
+        assert!(serde_json::from_str::<Recurrence>(&json).is_err());
+
+        let json = serde_json::to_string(&Recurrence::Every(DAY)).unwrap();
+        assert_eq!(
+            Recurrence::Every(DAY),
+            serde_json::from_str::<Recurrence>(&json).unwrap()
+        );
+    }
+}
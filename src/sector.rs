@@ -0,0 +1,167 @@
+use crate::{Distance, Length, Position, Vector2};
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// A high-precision position stored as a grid sector plus an offset within that sector.
+///
+/// The offset is always within `0..SECTOR_SIZE` on each axis, so its precision does not
+/// degrade with distance from the origin. Differences between nearby positions are exact.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct SectorPosition {
+    sector: Vector2<i64>,
+    offset: Distance,
+}
+
+impl SectorPosition {
+    /// 2^40 m, roughly 7.3 AU. A power of two keeps sector arithmetic exact.
+    pub const SECTOR_SIZE: Length = Length::in_m(1_099_511_627_776.0);
+
+    #[inline]
+    pub fn new(sector: Vector2<i64>, offset: Distance) -> Self {
+        let (x_sector, x) = Self::normalize(offset.x);
+        let (y_sector, y) = Self::normalize(offset.y);
+
+        Self {
+            sector: sector
+                + Vector2 {
+                    x: x_sector,
+                    y: y_sector,
+                },
+            offset: Distance { x, y },
+        }
+    }
+
+    #[inline]
+    pub const fn sector(&self) -> Vector2<i64> {
+        self.sector
+    }
+
+    /// Offset from the corner of the sector
+    #[inline]
+    pub const fn offset(&self) -> Distance {
+        self.offset
+    }
+
+    /// Position relative to the camera, in metres, for rendering
+    #[inline]
+    pub fn relative_to(self, camera: Self) -> Vector2<f32> {
+        let distance = self - camera;
+        Vector2 {
+            x: distance.x.value as f32,
+            y: distance.y.value as f32,
+        }
+    }
+
+    #[inline]
+    fn normalize(offset: Length) -> (i64, Length) {
+        let sectors = offset.value.div_euclid(Self::SECTOR_SIZE.value) as i64;
+        let remainder = offset.value.rem_euclid(Self::SECTOR_SIZE.value);
+
+        // tiny negative offsets round up to a whole sector
+        if remainder >= Self::SECTOR_SIZE.value {
+            (sectors + 1, Length::zero())
+        } else {
+            (sectors, Length::in_m(remainder))
+        }
+    }
+
+    #[inline]
+    fn sector_distance(sectors: i64) -> Length {
+        sectors as f64 * Self::SECTOR_SIZE
+    }
+}
+
+impl From<Position> for SectorPosition {
+    #[inline]
+    fn from(position: Position) -> Self {
+        Self::new(Vector2::default(), position.value)
+    }
+}
+
+impl From<SectorPosition> for Position {
+    #[inline]
+    fn from(position: SectorPosition) -> Self {
+        let x = SectorPosition::sector_distance(position.sector.x) + position.offset.x;
+        let y = SectorPosition::sector_distance(position.sector.y) + position.offset.y;
+        Position::from_origin(Distance { x, y })
+    }
+}
+
+impl Add<Distance> for SectorPosition {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Distance) -> Self {
+        Self::new(self.sector, self.offset + rhs)
+    }
+}
+
+impl AddAssign<Distance> for SectorPosition {
+    #[inline]
+    fn add_assign(&mut self, rhs: Distance) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Distance> for SectorPosition {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Distance) -> Self {
+        Self::new(self.sector, self.offset - rhs)
+    }
+}
+
+impl SubAssign<Distance> for SectorPosition {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Distance) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub for SectorPosition {
+    type Output = Distance;
+    #[inline]
+    fn sub(self, rhs: Self) -> Distance {
+        let sectors = self.sector - rhs.sector;
+        Distance {
+            x: Self::sector_distance(sectors.x) + (self.offset.x - rhs.offset.x),
+            y: Self::sector_distance(sectors.y) + (self.offset.y - rhs.offset.y),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn offset_is_normalized() {
+        let size = SectorPosition::SECTOR_SIZE.value;
+        let position = SectorPosition::new(Vector2 { x: 1, y: 1 }, Vector2::in_m(-1.0, size + 2.0));
+
+        assert_eq!(Vector2 { x: 0, y: 2 }, position.sector());
+        assert_eq!(Vector2::in_m(size - 1.0, 2.0), position.offset());
+
+        let position = SectorPosition::new(Vector2::default(), Vector2::in_m(-1e-30, 0.0));
+
+        assert_eq!(Vector2::default(), position.sector());
+        assert_eq!(Vector2::in_m(0.0, 0.0), position.offset());
+    }
+
+    #[test]
+    fn nearby_difference_is_exact_far_from_origin() {
+        let star = SectorPosition::from(Position::in_ly(50_000.0, -30_000.0));
+        let offset = Vector2::in_m(1.5, -0.25);
+
+        let fleet = star + offset;
+
+        assert_eq!(offset, fleet - star);
+        assert_eq!(Vector2 { x: 1.5, y: -0.25 }, fleet.relative_to(star));
+        assert_ne!(offset, Position::from(fleet) - Position::from(star));
+    }
+
+    #[test]
+    fn position_round_trip() {
+        let position = Position::in_ly(-12.5, 30_000.0);
+
+        assert_eq!(position, Position::from(SectorPosition::from(position)));
+    }
+}
@@ -0,0 +1,274 @@
+use crate::constants::{C, H, K_B};
+use crate::{FluxDensity, Frequency, Length, Temperature};
+use std::f64::consts::PI;
+
+// Power emitted per unit area and per steradian
+scalar! {
+    struct Radiance(f64) {
+        fn in_w_per_m2_sr(watts_per_meter_squared_steradian) -> Self;
+    }
+}
+
+// Power emitted per unit area, per steradian and per unit wavelength
+scalar! {
+    struct SpectralRadiance(f64) {
+        fn in_w_per_m2_sr_m(watts_per_meter_squared_steradian_meter) -> Self;
+    }
+}
+
+// Power emitted per unit area, per steradian and per unit frequency
+scalar! {
+    struct SpectralRadianceFrequency(f64) {
+        fn in_w_per_m2_sr_hz(watts_per_meter_squared_steradian_hertz) -> Self;
+    }
+}
+
+scalar_div!(Radiance | Length = SpectralRadiance);
+scalar_div!(Radiance | Frequency = SpectralRadianceFrequency);
+
+impl FluxDensity {
+    /// Power emitted per unit area by a surface that looks equally bright from every direction,
+    /// which integrates its radiance over a hemisphere to give a factor of π steradians
+    /// https://en.wikipedia.org/wiki/Lambert%27s_cosine_law
+    #[inline]
+    pub fn of_lambertian(radiance: Radiance) -> Self {
+        FluxDensity::in_w_per_m2(PI * radiance.value)
+    }
+}
+
+impl SpectralRadiance {
+    /// Planck's law per unit wavelength
+    /// https://en.wikipedia.org/wiki/Planck%27s_law
+    pub fn blackbody(temperature: Temperature, wavelength: Length) -> Self {
+        let (h, c, k) = (H.value.value, C.value.value, K_B.value.value);
+        let lambda = wavelength.value;
+
+        let exponent = h * c / (lambda * k * temperature.kelvin());
+        if exponent > MAX_EXPONENT {
+            return Self::zero();
+        }
+
+        Self::in_w_per_m2_sr_m(2.0 * h * c * c / lambda.powi(5) / exponent.exp_m1())
+    }
+}
+
+impl SpectralRadianceFrequency {
+    /// Planck's law per unit frequency
+    pub fn blackbody(temperature: Temperature, frequency: Frequency) -> Self {
+        let (h, c, k) = (H.value.value, C.value.value, K_B.value.value);
+        let nu = frequency.value;
+
+        let exponent = h * nu / (k * temperature.kelvin());
+        if exponent > MAX_EXPONENT {
+            return Self::zero();
+        }
+
+        Self::in_w_per_m2_sr_hz(2.0 * h * nu.powi(3) / (c * c) / exponent.exp_m1())
+    }
+}
+
+/// Beyond this the radiance is too small to represent
+const MAX_EXPONENT: f64 = 700.0;
+
+impl Length {
+    /// Wavelength at which a black body's spectral radiance peaks
+    /// https://en.wikipedia.org/wiki/Wien%27s_displacement_law
+    #[inline]
+    pub fn of_wien_peak(temperature: Temperature) -> Self {
+        const WIEN: f64 = 2.897_771_955e-3;
+        Length::in_m(WIEN / temperature.kelvin())
+    }
+}
+
+impl FluxDensity {
+    /// Power emitted per unit area by a black body between two wavelengths,
+    /// which over all wavelengths is `FluxDensity::blackbody`.
+    ///
+    /// Returns `None` unless `0 < from <= to`.
+    pub fn blackbody_band(temperature: Temperature, from: Length, to: Length) -> Option<Self> {
+        if !(from > Length::zero() && to >= from && to.value.is_finite()) {
+            return None;
+        }
+
+        // Simpson's rule over the logarithm of the wavelength
+        const STEPS: usize = 1024;
+        let start = from.value.ln();
+        let step = (to.value.ln() - start) / STEPS as f64;
+
+        let sum = (0..=STEPS)
+            .map(|i| {
+                let weight = match i {
+                    0 | STEPS => 1.0,
+                    i if i % 2 == 1 => 4.0,
+                    _ => 2.0,
+                };
+                let wavelength = Length::in_m((start + step * i as f64).exp());
+                SpectralRadiance::blackbody(temperature, wavelength) * wavelength * weight
+            })
+            .sum::<Radiance>();
+
+        Some(FluxDensity::of_lambertian(sum * (step / 3.0)))
+    }
+}
+
+/// Linear RGB in the sRGB primaries, with components in `0..=1`
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct LinearRgb {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+}
+
+/// Gamma-encoded sRGB, with components in `0..=1`
+/// https://en.wikipedia.org/wiki/SRGB
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Srgb {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+}
+
+impl LinearRgb {
+    /// The colour of a black body, scaled so that its brightest component is one
+    pub fn of_blackbody(temperature: Temperature) -> Self {
+        let (mut x, mut y, mut z) = (0.0, 0.0, 0.0);
+
+        for nm in 380..=780 {
+            let nm = nm as f64;
+            let radiance = SpectralRadiance::blackbody(temperature, Length::in_m(nm * 1e-9)).value;
+            let (x_bar, y_bar, z_bar) = cie_1931(nm);
+
+            x += radiance * x_bar;
+            y += radiance * y_bar;
+            z += radiance * z_bar;
+        }
+
+        let r = 3.2406 * x - 1.5372 * y - 0.4986 * z;
+        let g = -0.9689 * x + 1.8758 * y + 0.0415 * z;
+        let b = 0.0557 * x - 0.2040 * y + 1.0570 * z;
+
+        let max = r.max(g).max(b);
+        if max <= 0.0 {
+            return Self::default();
+        }
+
+        Self {
+            r: r.max(0.0) / max,
+            g: g.max(0.0) / max,
+            b: b.max(0.0) / max,
+        }
+    }
+}
+
+impl Srgb {
+    #[inline]
+    pub fn of_blackbody(temperature: Temperature) -> Self {
+        LinearRgb::of_blackbody(temperature).into()
+    }
+
+    #[inline]
+    pub fn to_bytes(self) -> [u8; 3] {
+        let byte = |c: f64| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        [byte(self.r), byte(self.g), byte(self.b)]
+    }
+}
+
+impl From<LinearRgb> for Srgb {
+    #[inline]
+    fn from(value: LinearRgb) -> Self {
+        let encode = |c: f64| {
+            if c <= 0.003_130_8 {
+                12.92 * c
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            }
+        };
+
+        Self {
+            r: encode(value.r),
+            g: encode(value.g),
+            b: encode(value.b),
+        }
+    }
+}
+
+/// Multi-lobe Gaussian fit to the CIE 1931 2° colour matching functions, by wavelength in nm
+/// https://jcgt.org/published/0002/02/01/
+fn cie_1931(nm: f64) -> (f64, f64, f64) {
+    let g = |mu: f64, below: f64, above: f64| {
+        let sigma = if nm < mu { below } else { above };
+        (-0.5 * ((nm - mu) / sigma).powi(2)).exp()
+    };
+
+    let x =
+        1.056 * g(599.8, 37.9, 31.0) + 0.362 * g(442.0, 16.0, 26.7) - 0.065 * g(501.1, 20.4, 26.2);
+    let y = 0.821 * g(568.8, 46.9, 40.5) + 0.286 * g(530.9, 16.3, 31.1);
+    let z = 1.217 * g(437.0, 11.8, 36.0) + 0.681 * g(459.0, 26.0, 13.8);
+
+    (x, y, z)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::catalogue::SUN;
+
+    #[test]
+    fn wien_peak() {
+        let peak = Length::of_wien_peak(SUN.temperature);
+        let radiance = |wavelength| SpectralRadiance::blackbody(SUN.temperature, wavelength);
+
+        assert!((peak - Length::in_m(502e-9)).abs() < Length::in_m(1e-9));
+        assert!(radiance(peak) > radiance(peak * 0.99));
+        assert!(radiance(peak) > radiance(peak * 1.01));
+        assert_eq!(SpectralRadiance::zero(), radiance(Length::zero()));
+    }
+
+    #[test]
+    fn wavelength_and_frequency_agree() {
+        let wavelength = Length::in_m(1e-6);
+        let frequency = C.value / wavelength;
+
+        let per_wavelength = SpectralRadiance::blackbody(SUN.temperature, wavelength);
+        let per_frequency = SpectralRadianceFrequency::blackbody(SUN.temperature, frequency);
+        let converted = per_frequency * frequency / wavelength;
+
+        assert!((converted / per_wavelength - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn band_integrates_to_stefan_boltzmann() {
+        let temperature = SUN.temperature;
+        let total = FluxDensity::blackbody(temperature);
+        let band = |from, to| {
+            FluxDensity::blackbody_band(temperature, Length::in_m(from), Length::in_m(to))
+        };
+
+        assert!((band(1e-8, 1e-3).unwrap() / total - 1.0).abs() < 1e-6);
+        assert!((band(380e-9, 780e-9).unwrap() / total - 0.45).abs() < 0.02);
+        assert_eq!(None, band(0.0, 1e-6));
+        assert_eq!(None, band(1e-6, 1e-7));
+    }
+
+    #[test]
+    fn blackbody_colours() {
+        let colour = |kelvin| LinearRgb::of_blackbody(Temperature::in_k(kelvin));
+
+        let ember = colour(1500.0);
+        let white = colour(6500.0);
+        let blue = colour(15_000.0);
+
+        assert!(ember.r > ember.g && ember.g > ember.b);
+        assert!(white.r.min(white.g).min(white.b) > 0.9);
+        assert!(blue.b > blue.g && blue.g > blue.r);
+        assert_eq!(
+            [255, 255, 255],
+            Srgb::from(LinearRgb {
+                r: 1.0,
+                g: 1.0,
+                b: 1.0
+            })
+            .to_bytes()
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use super::{Duration, Frequency, Length};
+use super::{Duration, Frequency, GravitationalParameter, Length};
 
 scalar! {
     struct Speed(f64) {
@@ -6,8 +6,17 @@ scalar! {
     }
 }
 
+scalar! {
+    struct SpeedSquared(f64) {
+        fn in_m2_per_s2(meters_squared_per_second_squared) -> Self;
+    }
+}
+
 scalar_div! { Length | Duration = Speed }
 scalar_div! { Speed | Frequency = Length }
+scalar_div! { GravitationalParameter | Length = SpeedSquared }
+
+scalar_squared!(Speed ^ 2 = SpeedSquared);
 
 impl Speed {
     pub const C: Speed = Speed::in_m_per_s(299792458.0);
@@ -1,5 +1,5 @@
-use crate::{Angle, Vector3};
-use std::ops::Mul;
+use crate::{Angle, New, Vector3};
+use std::ops::{Div, Mul};
 
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Spherical<T> {
@@ -19,6 +19,33 @@ impl<T> Spherical<T> {
     }
 }
 
+impl<T> Spherical<T>
+where
+    T: New<Value = f64> + Div<T, Output = f64> + Default + PartialEq + Copy,
+{
+    /// Returns the spherical coordinates of a vector, with both angles set to zero for the zero vector
+    #[inline]
+    pub fn from_vector(vector: Vector3<T>) -> Self {
+        let magnitude = vector.magnitude();
+
+        if magnitude == T::default() {
+            return Self {
+                magnitude,
+                phi: Angle::zero(),
+                theta: Angle::zero(),
+            };
+        }
+
+        let cos_phi = (vector.z / magnitude).clamp(-1.0, 1.0);
+
+        Self {
+            magnitude,
+            phi: Angle::acos(cos_phi),
+            theta: Angle::atan2(vector.y.value(), vector.x.value()),
+        }
+    }
+}
+
 impl<T: Mul<f64, Output = T> + Copy> Spherical<T> {
     #[inline]
     pub fn vector(self) -> Vector3<T> {
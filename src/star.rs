@@ -0,0 +1,258 @@
+use crate::catalogue::SUN;
+use crate::constants::SIGMA;
+use crate::{Duration, Length, Mass, Power, Sqrt, Squared, Temperature, PC, YR};
+use std::f64::consts::PI;
+use std::fmt::{Display, Formatter};
+
+/// IAU nominal solar luminosity
+pub const SOLAR_LUMINOSITY: Power = Power::in_watts(3.828e26);
+
+/// Approximate relations for a star on the main sequence, scaled from the Sun
+/// https://en.wikipedia.org/wiki/Main_sequence
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MainSequence {
+    pub mass: Mass,
+}
+
+impl MainSequence {
+    #[inline]
+    pub const fn new(mass: Mass) -> Self {
+        Self { mass }
+    }
+
+    /// The usual piecewise power law, with each segment scaled to meet its neighbours
+    /// so that luminosity rises continuously with mass
+    /// https://en.wikipedia.org/wiki/Mass%E2%80%93luminosity_relation
+    pub fn luminosity(&self) -> Power {
+        let m = self.solar_masses();
+        let l = if m < 0.43 {
+            0.43f64.powi(4) * (m / 0.43).powf(2.3)
+        } else if m < 2.0 {
+            m.powi(4)
+        } else if m < 55.0 {
+            16.0 * (m / 2.0).powf(3.5)
+        } else {
+            16.0 * 27.5f64.powf(3.5) * (m / 55.0)
+        };
+
+        SOLAR_LUMINOSITY * l
+    }
+
+    pub fn radius(&self) -> Length {
+        let m = self.solar_masses();
+        let exponent = if m < 1.0 { 0.8 } else { 0.57 };
+
+        SUN.radius * m.powf(exponent)
+    }
+
+    /// Effective temperature of a black body with the star's luminosity and radius
+    pub fn temperature(&self) -> Temperature {
+        let flux = self.luminosity() / (self.radius().squared() * (4.0 * PI));
+        Temperature::from_origin((flux / SIGMA.value).sqrt().sqrt())
+    }
+
+    /// Time spent on the main sequence, about ten billion years for the Sun
+    pub fn lifetime(&self) -> Duration {
+        let l = self.luminosity() / SOLAR_LUMINOSITY;
+        YR * (1e10 * self.solar_masses() / l)
+    }
+
+    #[inline]
+    pub fn spectral_class(&self) -> SpectralClass {
+        SpectralClass::from_temperature(self.temperature())
+    }
+
+    #[inline]
+    fn solar_masses(&self) -> f64 {
+        self.mass / SUN.mass
+    }
+}
+
+/// Harvard spectral classes from hottest to coolest
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum SpectralType {
+    O,
+    B,
+    A,
+    F,
+    G,
+    K,
+    M,
+}
+
+impl SpectralType {
+    pub const ALL: [SpectralType; 7] = [
+        SpectralType::O,
+        SpectralType::B,
+        SpectralType::A,
+        SpectralType::F,
+        SpectralType::G,
+        SpectralType::K,
+        SpectralType::M,
+    ];
+
+    /// Range of effective temperatures in kelvin, from coolest to hottest
+    pub const fn temperature_range(self) -> (f64, f64) {
+        match self {
+            SpectralType::O => (30_000.0, 50_000.0),
+            SpectralType::B => (10_000.0, 30_000.0),
+            SpectralType::A => (7_500.0, 10_000.0),
+            SpectralType::F => (6_000.0, 7_500.0),
+            SpectralType::G => (5_200.0, 6_000.0),
+            SpectralType::K => (3_700.0, 5_200.0),
+            SpectralType::M => (2_400.0, 3_700.0),
+        }
+    }
+}
+
+/// A spectral type with a subclass from 0 (hottest) to 9, such as G2 for the Sun
+/// https://en.wikipedia.org/wiki/Stellar_classification
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct SpectralClass {
+    pub spectral_type: SpectralType,
+    pub subclass: u8,
+}
+
+impl SpectralClass {
+    /// Temperatures beyond either end of the sequence are classed as O0 or M9
+    pub fn from_temperature(temperature: Temperature) -> Self {
+        let kelvin = temperature.kelvin();
+        let spectral_type = SpectralType::ALL
+            .iter()
+            .copied()
+            .find(|class| kelvin >= class.temperature_range().0)
+            .unwrap_or(SpectralType::M);
+
+        let (min, max) = spectral_type.temperature_range();
+        let subclass = (10.0 * (max - kelvin) / (max - min))
+            .floor()
+            .clamp(0.0, 9.0);
+
+        Self {
+            spectral_type,
+            subclass: subclass as u8,
+        }
+    }
+}
+
+impl Display for SpectralClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}{}", self.spectral_type, self.subclass)
+    }
+}
+
+impl Power {
+    /// Absolute bolometric magnitude, where brighter stars have lower magnitudes
+    /// https://en.wikipedia.org/wiki/Absolute_magnitude
+    #[inline]
+    pub fn absolute_magnitude(self) -> f64 {
+        Self::SOLAR_MAGNITUDE - 2.5 * (self / SOLAR_LUMINOSITY).log10()
+    }
+
+    #[inline]
+    pub fn from_absolute_magnitude(magnitude: f64) -> Self {
+        SOLAR_LUMINOSITY * 10f64.powf((Self::SOLAR_MAGNITUDE - magnitude) / 2.5)
+    }
+
+    /// Absolute bolometric magnitude of the Sun
+    const SOLAR_MAGNITUDE: f64 = 4.74;
+}
+
+/// Magnitude of a star with the given absolute magnitude seen from `distance`
+#[inline]
+pub fn apparent_magnitude(absolute_magnitude: f64, distance: Length) -> f64 {
+    absolute_magnitude + distance_modulus(distance)
+}
+
+/// Magnitude the star would have if seen from 10 parsecs
+#[inline]
+pub fn absolute_magnitude(apparent_magnitude: f64, distance: Length) -> f64 {
+    apparent_magnitude - distance_modulus(distance)
+}
+
+/// https://en.wikipedia.org/wiki/Distance_modulus
+#[inline]
+pub fn distance_modulus(distance: Length) -> f64 {
+    5.0 * (distance / (10.0 * PC)).log10()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::catalogue::{BETELGEUSE, PROXIMA_CENTAURI, SIRIUS_A};
+    use crate::{AU, LY};
+
+    #[test]
+    fn sun_on_main_sequence() {
+        let sun = MainSequence::new(SUN.mass);
+
+        assert_eq!(SOLAR_LUMINOSITY, sun.luminosity());
+        assert_eq!(SUN.radius, sun.radius());
+        assert!((sun.temperature().kelvin() - SUN.temperature.kelvin()).abs() < 1.0);
+        assert!((sun.lifetime() / YR - 1e10).abs() < 1.0);
+        assert_eq!("G2", sun.spectral_class().to_string());
+    }
+
+    #[test]
+    fn heavier_stars_are_hotter_and_shorter_lived() {
+        let masses = [0.1, 0.5, 1.0, 3.0, 10.0, 60.0];
+        let stars = masses
+            .iter()
+            .map(|m| MainSequence::new(SUN.mass * *m))
+            .collect::<Vec<_>>();
+
+        for pair in stars.windows(2) {
+            assert!(pair[0].luminosity() < pair[1].luminosity());
+            assert!(pair[0].radius() < pair[1].radius());
+            assert!(pair[0].lifetime() > pair[1].lifetime());
+            assert!(pair[0].spectral_class() >= pair[1].spectral_class());
+        }
+    }
+
+    #[test]
+    fn luminosity_is_continuous() {
+        let luminosity = |m: f64| MainSequence::new(SUN.mass * m).luminosity();
+
+        for breakpoint in [0.43, 2.0, 55.0].iter() {
+            let below = luminosity(breakpoint * (1.0 - 1e-9));
+            let above = luminosity(breakpoint * (1.0 + 1e-9));
+
+            assert!(below < luminosity(*breakpoint) && luminosity(*breakpoint) < above);
+            assert!(above / below - 1.0 < 1e-7);
+        }
+    }
+
+    #[test]
+    fn spectral_classes() {
+        let class = |body: crate::catalogue::Body| {
+            SpectralClass::from_temperature(body.temperature).to_string()
+        };
+
+        assert_eq!("A0", class(SIRIUS_A));
+        assert_eq!("M5", class(PROXIMA_CENTAURI));
+        assert_eq!("M0", class(BETELGEUSE));
+        assert_eq!(
+            "O0",
+            SpectralClass::from_temperature(Temperature::in_k(80_000.0)).to_string()
+        );
+        assert_eq!(
+            "M9",
+            SpectralClass::from_temperature(Temperature::in_k(1_000.0)).to_string()
+        );
+    }
+
+    #[test]
+    fn magnitudes() {
+        let sun = SOLAR_LUMINOSITY.absolute_magnitude();
+        let apparent = apparent_magnitude(sun, AU);
+
+        assert!((sun - 4.74).abs() < 1e-12);
+        assert!((apparent + 26.83).abs() < 0.01);
+        assert!((absolute_magnitude(apparent, AU) - sun).abs() < 1e-9);
+        assert!((distance_modulus(10.0 * PC)).abs() < 1e-12);
+        assert!((distance_modulus(32.6156 * LY)).abs() < 1e-4);
+        assert!(
+            (Power::from_absolute_magnitude(sun - 5.0) / SOLAR_LUMINOSITY - 100.0).abs() < 1e-9
+        );
+    }
+}
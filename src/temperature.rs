@@ -1,13 +1,160 @@
-pub const K: Temperature = Temperature::in_k(1.0);
+use crate::{New, Point, Squared};
+use std::ops::Div;
+
+pub const K: TemperatureDelta = TemperatureDelta::in_k(1.0);
+
+/// Absolute (thermodynamic) temperature, measured from absolute zero.
+///
+/// Temperatures are points on an affine scale: the difference between two temperatures is a
+/// `TemperatureDelta`, and only deltas can be added to a temperature or scaled.
+pub type Temperature = Point<TemperatureDelta>;
 
 scalar! {
-    struct Temperature(f64) {
+    struct TemperatureDelta(f64) {
         fn in_k(kelvin) -> Self;
     }
 }
 
+scalar! {
+    struct TemperatureSquared(f64) {
+        fn in_k2(kelvin_squared) -> Self;
+    }
+}
+
+scalar! {
+    struct TemperatureFourth(f64) {
+        fn in_k4(kelvin_fourth) -> Self;
+    }
+}
+
+scalar_squared!(TemperatureDelta ^ 2 = TemperatureSquared);
+scalar_squared!(TemperatureSquared ^ 2 = TemperatureFourth);
+
 impl Temperature {
+    #[inline]
+    pub const fn in_k(kelvin: f64) -> Self {
+        Self::from_origin(TemperatureDelta::in_k(kelvin))
+    }
+
+    #[inline]
     pub const fn in_c(celsius: f64) -> Self {
-        Self::in_k(celsius + 273.15)
+        Self::in_k(celsius + Self::ZERO_C_IN_K)
+    }
+
+    #[inline]
+    pub const fn in_f(fahrenheit: f64) -> Self {
+        Self::in_r(fahrenheit + Self::ZERO_F_IN_R)
+    }
+
+    #[inline]
+    pub const fn in_r(rankine: f64) -> Self {
+        Self::in_k(rankine * Self::K_PER_R)
+    }
+
+    #[inline]
+    pub const fn kelvin(self) -> f64 {
+        self.value.value
+    }
+
+    #[inline]
+    pub const fn celsius(self) -> f64 {
+        self.kelvin() - Self::ZERO_C_IN_K
+    }
+
+    #[inline]
+    pub const fn fahrenheit(self) -> f64 {
+        self.rankine() - Self::ZERO_F_IN_R
+    }
+
+    #[inline]
+    pub const fn rankine(self) -> f64 {
+        self.kelvin() / Self::K_PER_R
+    }
+
+    /// Absolute zero
+    pub const ZERO: Self = Self::in_k(0.0);
+
+    const ZERO_C_IN_K: f64 = 273.15;
+
+    const ZERO_F_IN_R: f64 = 459.67;
+
+    const K_PER_R: f64 = 5.0 / 9.0;
+}
+
+impl const New for Temperature {
+    type Value = f64;
+
+    #[inline]
+    fn new(kelvin: f64) -> Self {
+        Self::in_k(kelvin)
+    }
+
+    #[inline]
+    fn value(self) -> f64 {
+        self.kelvin()
+    }
+}
+
+impl TemperatureDelta {
+    #[inline]
+    pub const fn in_c(celsius: f64) -> Self {
+        Self::in_k(celsius)
+    }
+
+    #[inline]
+    pub const fn in_f(fahrenheit: f64) -> Self {
+        Self::in_r(fahrenheit)
+    }
+
+    #[inline]
+    pub const fn in_r(rankine: f64) -> Self {
+        Self::in_k(rankine * Temperature::K_PER_R)
+    }
+}
+
+impl Div for Temperature {
+    type Output = f64;
+    #[inline]
+    fn div(self, rhs: Self) -> f64 {
+        self.value / rhs.value
+    }
+}
+
+/// Squares the absolute temperature, as in the Stefan-Boltzmann law
+impl Squared for Temperature {
+    type Output = TemperatureSquared;
+    #[inline]
+    fn squared(self) -> Self::Output {
+        TemperatureSquared::new(self.kelvin() * self.kelvin())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Sqrt;
+
+    #[test]
+    fn temperature_scales() {
+        let boiling = Temperature::in_c(100.0);
+
+        assert_eq!(373.15, boiling.kelvin());
+        assert!((212.0 - boiling.fahrenheit()).abs() < 1e-9);
+        assert!((671.67 - boiling.rankine()).abs() < 1e-9);
+        assert!((100.0 - Temperature::in_f(212.0).celsius()).abs() < 1e-9);
+        assert_eq!(Temperature::ZERO, Temperature::in_r(0.0));
+    }
+
+    #[test]
+    fn affine_arithmetic() {
+        let freezing = Temperature::in_c(0.0);
+        let boiling = Temperature::in_c(100.0);
+        let delta = TemperatureDelta::in_c(100.0);
+
+        assert_eq!(delta, boiling - freezing);
+        assert_eq!(boiling, freezing + delta);
+        assert_eq!(freezing, boiling - delta);
+        assert_eq!(TemperatureDelta::in_k(5.0), TemperatureDelta::in_f(9.0));
+        assert_eq!(delta, delta.squared().sqrt());
     }
 }
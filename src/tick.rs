@@ -0,0 +1,284 @@
+use crate::{Duration, Point, TimeIndex};
+use std::ops::*;
+
+/// Elapsed game time as an integer number of nanoseconds.
+///
+/// Unlike `TimeIndex`, the resolution does not degrade as time passes, and repeatedly adding a
+/// fixed `TickDuration` gives the same result on every machine.
+pub type TickIndex = Point<TickDuration>;
+
+/// An integer number of nanoseconds.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct TickDuration {
+    nanoseconds: i128,
+}
+
+impl TickDuration {
+    #[inline]
+    pub const fn in_ns(nanoseconds: i128) -> Self {
+        Self { nanoseconds }
+    }
+
+    #[inline]
+    pub const fn in_us(microseconds: i128) -> Self {
+        Self::in_ns(microseconds * 1_000)
+    }
+
+    #[inline]
+    pub const fn in_ms(milliseconds: i128) -> Self {
+        Self::in_ns(milliseconds * 1_000_000)
+    }
+
+    #[inline]
+    pub const fn in_s(seconds: i128) -> Self {
+        Self::in_ns(seconds * Self::NS_PER_S)
+    }
+
+    /// The step for a fixed update rate, rounded to the nearest nanosecond
+    ///
+    /// # Panics
+    /// If `steps` is zero
+    #[inline]
+    pub const fn per_second(steps: u32) -> Self {
+        assert!(
+            steps > 0,
+            "a fixed update rate needs at least one step per second"
+        );
+        let steps = steps as i128;
+        Self::in_ns((Self::NS_PER_S + steps / 2) / steps)
+    }
+
+    #[inline]
+    pub const fn ns(self) -> i128 {
+        self.nanoseconds
+    }
+
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::in_ns(0)
+    }
+
+    const NS_PER_S: i128 = 1_000_000_000;
+}
+
+impl TickIndex {
+    #[inline]
+    pub const fn in_ns(nanoseconds: i128) -> Self {
+        Self::from_origin(TickDuration::in_ns(nanoseconds))
+    }
+
+    #[inline]
+    pub const fn ns(self) -> i128 {
+        self.value.nanoseconds
+    }
+}
+
+/// Rounds to the nearest nanosecond
+impl From<Duration> for TickDuration {
+    #[inline]
+    fn from(duration: Duration) -> Self {
+        Self::in_ns((duration.value * 1e9).round() as i128)
+    }
+}
+
+impl From<TickDuration> for Duration {
+    #[inline]
+    fn from(duration: TickDuration) -> Self {
+        let seconds = duration.nanoseconds.div_euclid(TickDuration::NS_PER_S);
+        let nanoseconds = duration.nanoseconds.rem_euclid(TickDuration::NS_PER_S);
+        Duration::in_s(seconds as f64 + nanoseconds as f64 / 1e9)
+    }
+}
+
+/// Rounds to the nearest nanosecond
+impl From<TimeIndex> for TickIndex {
+    #[inline]
+    fn from(time: TimeIndex) -> Self {
+        Self::from_origin(time.value.into())
+    }
+}
+
+impl From<TickIndex> for TimeIndex {
+    #[inline]
+    fn from(time: TickIndex) -> Self {
+        Self::from_origin(time.value.into())
+    }
+}
+
+impl Add for TickDuration {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Self::in_ns(self.nanoseconds + rhs.nanoseconds)
+    }
+}
+
+impl AddAssign for TickDuration {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.nanoseconds += rhs.nanoseconds;
+    }
+}
+
+impl Sub for TickDuration {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Self::in_ns(self.nanoseconds - rhs.nanoseconds)
+    }
+}
+
+impl SubAssign for TickDuration {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.nanoseconds -= rhs.nanoseconds;
+    }
+}
+
+impl Neg for TickDuration {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::in_ns(-self.nanoseconds)
+    }
+}
+
+impl Mul<i128> for TickDuration {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: i128) -> Self {
+        Self::in_ns(self.nanoseconds * rhs)
+    }
+}
+
+impl Mul<TickDuration> for i128 {
+    type Output = TickDuration;
+    #[inline]
+    fn mul(self, rhs: TickDuration) -> TickDuration {
+        rhs * self
+    }
+}
+
+/// Number of whole steps of `rhs` that fit in `self`
+impl Div for TickDuration {
+    type Output = i128;
+    #[inline]
+    fn div(self, rhs: Self) -> i128 {
+        self.nanoseconds.div_euclid(rhs.nanoseconds)
+    }
+}
+
+impl Rem for TickDuration {
+    type Output = Self;
+    #[inline]
+    fn rem(self, rhs: Self) -> Self {
+        Self::in_ns(self.nanoseconds.rem_euclid(rhs.nanoseconds))
+    }
+}
+
+impl Add<Duration> for TickIndex {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Duration) -> Self {
+        self + TickDuration::from(rhs)
+    }
+}
+
+impl AddAssign<Duration> for TickIndex {
+    #[inline]
+    fn add_assign(&mut self, rhs: Duration) {
+        *self += TickDuration::from(rhs);
+    }
+}
+
+impl Sub<Duration> for TickIndex {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Duration) -> Self {
+        self - TickDuration::from(rhs)
+    }
+}
+
+impl SubAssign<Duration> for TickIndex {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Duration) {
+        *self -= TickDuration::from(rhs);
+    }
+}
+
+impl Div for TickIndex {
+    type Output = f64;
+    #[inline]
+    fn div(self, rhs: Self) -> f64 {
+        self.value.nanoseconds as f64 / rhs.value.nanoseconds as f64
+    }
+}
+
+impl Div<Duration> for TickIndex {
+    type Output = f64;
+    #[inline]
+    fn div(self, rhs: Duration) -> f64 {
+        Duration::from(self.value) / rhs
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::YR;
+
+    #[test]
+    fn fixed_steps_accumulate_exactly() {
+        let step = TickDuration::per_second(60);
+        let start = TickIndex::default();
+
+        let mut time = start;
+        for _ in 0..10_000 {
+            time += step;
+        }
+
+        assert_eq!(start + step * 10_000, time);
+        assert_eq!(10_000, (time - start) / step);
+        assert_eq!(TickDuration::zero(), (time - start) % step);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one step per second")]
+    fn zero_steps_per_second() {
+        TickDuration::per_second(0);
+    }
+
+    #[test]
+    fn resolution_does_not_degrade() {
+        let nanosecond = TickDuration::in_ns(1);
+        let late = TickIndex::from(TimeIndex::default() + 1000.0 * YR);
+
+        assert_eq!(nanosecond, (late + nanosecond) - late);
+
+        let late_float = TimeIndex::from(late);
+        assert_eq!(
+            Duration::zero(),
+            (late_float + Duration::in_s(1e-9)) - late_float
+        );
+    }
+
+    #[test]
+    fn duration_conversion() {
+        let duration = Duration::in_s(1.5);
+        let ticks = TickDuration::from(duration);
+
+        assert_eq!(TickDuration::in_ms(1500), ticks);
+        assert_eq!(duration, Duration::from(ticks));
+        assert_eq!(Duration::in_s(-0.25), TickDuration::in_ms(-250).into());
+    }
+
+    #[test]
+    fn time_index_api() {
+        let t = TickIndex::in_ns(3_000_000_000);
+
+        assert_eq!(TickIndex::in_ns(4_500_000_000), t + Duration::in_s(1.5));
+        assert_eq!(TickIndex::in_ns(1_500_000_000), t - Duration::in_s(1.5));
+        assert_eq!(2.0, t / TickIndex::in_ns(1_500_000_000));
+        assert_eq!(3.0, t / Duration::in_s(1.0));
+    }
+}
@@ -1,8 +1,9 @@
 use crate::*;
 use chrono::NaiveDateTime;
-use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::fmt::{Display, Formatter};
 use std::ops::*;
+use std::time::{Instant, SystemTime};
 
 pub type DateTime = NaiveDateTime;
 pub type StdDuration = std::time::Duration;
@@ -15,10 +16,7 @@ pub const DAY: Duration = Duration::in_hr(24.0);
 pub const YR: Duration = Duration::in_d(365.25);
 
 /// Elapsed game time in seconds.
-#[derive(Debug, Default, Copy, Clone, PartialEq)]
-pub struct TimeIndex {
-    pub value: Duration,
-}
+pub type TimeIndex = Point<Duration>;
 
 #[deprecated]
 pub type TimeFloat = TimeIndex;
@@ -36,9 +34,7 @@ impl TimeIndex {
 
     #[inline]
     fn new(value: f64) -> Self {
-        Self {
-            value: Duration::new(value),
-        }
+        Self::from_origin(Duration::new(value))
     }
 }
 
@@ -50,41 +46,6 @@ impl Div for TimeIndex {
     }
 }
 
-impl Add<Duration> for TimeIndex {
-    type Output = Self;
-    #[inline]
-    fn add(self, rhs: Duration) -> Self {
-        Self {
-            value: self.value + rhs,
-        }
-    }
-}
-
-impl AddAssign<Duration> for TimeIndex {
-    #[inline]
-    fn add_assign(&mut self, rhs: Duration) {
-        self.value += rhs;
-    }
-}
-
-impl Sub<Duration> for TimeIndex {
-    type Output = Self;
-    #[inline]
-    fn sub(self, rhs: Duration) -> Self {
-        Self {
-            value: self.value - rhs,
-        }
-    }
-}
-
-impl Sub for TimeIndex {
-    type Output = Duration;
-    #[inline]
-    fn sub(self, rhs: Self) -> Duration {
-        self.value - rhs.value
-    }
-}
-
 impl Div<Duration> for TimeIndex {
     type Output = f64;
     #[inline]
@@ -93,22 +54,6 @@ impl Div<Duration> for TimeIndex {
     }
 }
 
-impl Eq for TimeIndex {}
-
-impl PartialOrd for TimeIndex {
-    #[inline]
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.value.partial_cmp(&other.value)
-    }
-}
-
-impl Ord for TimeIndex {
-    #[inline]
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.value.partial_cmp(&other.value).unwrap()
-    }
-}
-
 scalar! {
     struct Duration(f64) {
         fn in_s(seconds) -> Self;
@@ -119,11 +64,9 @@ impl Duration {
     #[inline]
     pub fn of_orbit(radius: Length, mass: Mass) -> Self {
         use crate::constants::G;
-        use std::f64::consts::TAU;
 
-        const TAU_SQUARED_OVER_G: f64 = TAU * TAU / G;
-
-        Duration::in_s((radius.value.powi(3) * TAU_SQUARED_OVER_G / mass.value).sqrt())
+        let angular_frequency = (G.value * mass / (radius * radius.squared())).sqrt();
+        std::f64::consts::TAU / angular_frequency
     }
 
     #[inline]
@@ -156,24 +99,110 @@ impl Duration {
 impl From<ChronoDuration> for Duration {
     #[inline]
     fn from(duration: ChronoDuration) -> Self {
-        let seconds = duration.num_milliseconds() as f64 / 1e3;
-        Duration::in_s(seconds)
+        let nanoseconds = duration.subsec_nanos() as f64 / 1e9;
+        Duration::in_s(duration.num_seconds() as f64 + nanoseconds)
     }
 }
 
-impl From<Duration> for ChronoDuration {
+impl From<StdDuration> for Duration {
     #[inline]
-    fn from(duration: Duration) -> Self {
-        let microseconds = (duration.value * 1e6) as i64;
-        ChronoDuration::microseconds(microseconds)
+    fn from(duration: StdDuration) -> Self {
+        Duration::in_s(duration.as_secs_f64())
+    }
+}
+
+/// Rounds to the nearest nanosecond
+impl TryFrom<Duration> for ChronoDuration {
+    type Error = DurationConversionError;
+
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        if !duration.value.is_finite() {
+            return Err(DurationConversionError::NotFinite);
+        }
+
+        let seconds = duration.value.floor();
+        if seconds.abs() >= i64::MAX as f64 {
+            return Err(DurationConversionError::Overflow);
+        }
+        let nanoseconds = ((duration.value - seconds) * 1e9).round() as i64;
+
+        ChronoDuration::try_seconds(seconds as i64)
+            .and_then(|d| d.checked_add(&ChronoDuration::nanoseconds(nanoseconds)))
+            .ok_or(DurationConversionError::Overflow)
     }
 }
 
-impl From<Duration> for StdDuration {
+/// Rounds to the nearest nanosecond
+impl TryFrom<Duration> for StdDuration {
+    type Error = DurationConversionError;
+
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        if !duration.value.is_finite() {
+            return Err(DurationConversionError::NotFinite);
+        }
+        if duration.value < 0.0 {
+            return Err(DurationConversionError::Negative);
+        }
+
+        StdDuration::try_from_secs_f64(duration.value)
+            .map_err(|_| DurationConversionError::Overflow)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DurationConversionError {
+    Negative,
+    Overflow,
+    NotFinite,
+}
+
+impl Display for DurationConversionError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            Self::Negative => write!(f, "duration is negative"),
+            Self::Overflow => write!(f, "duration is out of range"),
+            Self::NotFinite => write!(f, "duration is not finite"),
+        }
+    }
+}
+
+impl std::error::Error for DurationConversionError {}
+
+impl TimeIndex {
+    /// The time elapsed since `epoch`, which is negative if `instant` is earlier
+    #[inline]
+    pub fn from_instant(instant: Instant, epoch: Instant) -> Self {
+        match instant.checked_duration_since(epoch) {
+            Some(elapsed) => Self::from_origin(elapsed.into()),
+            None => Self::from_origin(-Duration::from(epoch - instant)),
+        }
+    }
+
+    pub fn to_instant(self, epoch: Instant) -> Result<Instant, DurationConversionError> {
+        let instant = if self.value < Duration::zero() {
+            epoch.checked_sub(StdDuration::try_from(-self.value)?)
+        } else {
+            epoch.checked_add(StdDuration::try_from(self.value)?)
+        };
+        instant.ok_or(DurationConversionError::Overflow)
+    }
+
+    /// The time elapsed since `epoch`, which is negative if `time` is earlier
     #[inline]
-    fn from(duration: Duration) -> Self {
-        let microseconds = (duration.value * 1e6) as u64;
-        StdDuration::from_micros(microseconds)
+    pub fn from_system_time(time: SystemTime, epoch: SystemTime) -> Self {
+        match time.duration_since(epoch) {
+            Ok(elapsed) => Self::from_origin(elapsed.into()),
+            Err(error) => Self::from_origin(-Duration::from(error.duration())),
+        }
+    }
+
+    pub fn to_system_time(self, epoch: SystemTime) -> Result<SystemTime, DurationConversionError> {
+        let time = if self.value < Duration::zero() {
+            epoch.checked_sub(StdDuration::try_from(-self.value)?)
+        } else {
+            epoch.checked_add(StdDuration::try_from(self.value)?)
+        };
+        time.ok_or(DurationConversionError::Overflow)
     }
 }
 
@@ -199,10 +228,17 @@ scalar! {
     }
 }
 
+scalar! {
+    struct FrequencySquared(f64) {
+        fn per_s2(s2) -> Self;
+    }
+}
+
 scalar_div! { f64 | Duration = Frequency }
 scalar_div! { Duration | Frequency = DurationSquared }
 
 scalar_squared!(Duration ^ 2 = DurationSquared);
+scalar_squared!(Frequency ^ 2 = FrequencySquared);
 
 scalar_div!(Length | Acceleration = DurationSquared);
 
@@ -218,6 +254,64 @@ mod test {
         assert_eq!(Duration::in_s(1.0), one_second);
     }
 
+    #[test]
+    fn chrono_conversion_keeps_nanoseconds() {
+        let duration = ChronoDuration::nanoseconds(-1_500_000_001);
+
+        assert_eq!(Duration::in_s(-1.500_000_001), Duration::from(duration));
+        assert_eq!(
+            Ok(duration),
+            ChronoDuration::try_from(Duration::from(duration))
+        );
+        assert_eq!(
+            Err(DurationConversionError::Overflow),
+            ChronoDuration::try_from(Duration::MAX)
+        );
+    }
+
+    #[test]
+    fn std_conversion_is_checked() {
+        let duration = StdDuration::new(3, 250_000_001);
+
+        assert_eq!(Duration::in_s(3.250_000_001), Duration::from(duration));
+        assert_eq!(
+            Ok(duration),
+            StdDuration::try_from(Duration::from(duration))
+        );
+        assert_eq!(
+            Err(DurationConversionError::Negative),
+            StdDuration::try_from(Duration::in_s(-1.0))
+        );
+        assert_eq!(
+            Err(DurationConversionError::Overflow),
+            StdDuration::try_from(Duration::MAX)
+        );
+        assert_eq!(
+            Err(DurationConversionError::NotFinite),
+            StdDuration::try_from(Duration { value: f64::NAN })
+        );
+    }
+
+    #[test]
+    fn time_index_from_clocks() {
+        let epoch = Instant::now();
+        let time = TimeIndex::in_s(-2.5);
+        let instant = time.to_instant(epoch).unwrap();
+
+        assert_eq!(StdDuration::from_millis(2500), epoch - instant);
+        assert_eq!(time, TimeIndex::from_instant(instant, epoch));
+
+        let epoch = SystemTime::UNIX_EPOCH + StdDuration::from_secs(1_000_000);
+        let time = TimeIndex::in_d(1.5);
+        let system_time = time.to_system_time(epoch).unwrap();
+
+        assert_eq!(time, TimeIndex::from_system_time(system_time, epoch));
+        assert_eq!(
+            -time.value,
+            TimeIndex::from_system_time(epoch, system_time).value
+        );
+    }
+
     #[test]
     fn orbit_distance_and_period() {
         let mass = Mass::in_kg(1.0);
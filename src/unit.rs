@@ -70,9 +70,16 @@ impl Not for UnitInterval {
 }
 
 impl UnitInterval {
-    #[inline]
-    pub fn clamp(value: f64) -> Self {
-        Self(value.max(0.0).min(1.0))
+    /// Values outside `0..=1` are clamped to the nearest bound, and NaN becomes zero
+    #[inline]
+    pub const fn clamp(value: f64) -> Self {
+        if value >= 1.0 {
+            Self(1.0)
+        } else if value >= 0.0 {
+            Self(value)
+        } else {
+            Self(0.0)
+        }
     }
 
     #[inline]
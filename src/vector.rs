@@ -1,4 +1,4 @@
-use crate::{Dot, New, Sqrt};
+use crate::{Cross, Dot, New, Norm};
 use std::ops::*;
 
 macro_rules! sum {
@@ -228,26 +228,18 @@ macro_rules! vector {
         impl<T, F> $v <T>
         where
             T: New<Value = F> + Copy,
-            F: Add<F, Output = F> + Mul<F, Output = F> + Sqrt<Output = F> + Copy,
+            F: Norm,
         {
             #[inline]
             pub fn magnitude(&self) -> T {
-                T::new(self.magnitude_squared_inner().sqrt())
-            }
-
-            #[inline]
-            fn magnitude_squared_inner(&self) -> F {
-                $(
-                    let $f = self.$f.value();
-                )*
-                sum! { $( $f * $f, )* }
+                T::new(F::norm([ $( self.$f.value(), )* ]))
             }
         }
 
         impl<T, F> $v <T>
         where
             T: New<Value = F> + Default + PartialEq + Div<T, Output = F> + Copy,
-            F: Add<F, Output = F> + Mul<F, Output = F> + Sqrt<Output = F> + Copy,
+            F: Norm,
         {
             #[inline]
             pub fn unit_vector(&self) -> Option<$v<F>> {
@@ -400,6 +392,23 @@ where
     }
 }
 
+impl<T, U, V> Cross<Vector3<U>> for Vector3<T>
+where
+    T: Mul<U, Output = V> + Copy,
+    U: Copy,
+    V: Sub<V, Output = V>,
+{
+    type Output = Vector3<V>;
+
+    fn cross(self, rhs: Vector3<U>) -> Self::Output {
+        Vector3 {
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -577,4 +586,17 @@ mod test {
             Vector2::in_m(2.0, 0.0).unit_vector()
         );
     }
+
+    #[test]
+    fn cross_product() {
+        let x = Vector3::in_m(1.0, 0.0, 0.0);
+        let y = Vector3 {
+            x: 0.0,
+            y: 2.0,
+            z: 0.0,
+        };
+
+        assert_eq!(Vector3::in_m(0.0, 0.0, 2.0), x.cross(y));
+        assert_eq!(Vector3::in_m(0.0, 0.0, -2.0), y.cross(x));
+    }
 }